@@ -0,0 +1,502 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+use crate::split_once;
+
+/**
+ Life-like transition table: a dead cell is born when its count of live neighbours is in `birth`,
+ a live cell survives when it is in `survival`, every other cell is dead at the next generation.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct LifeRule {
+    pub birth: Vec<usize>,
+    pub survival: Vec<usize>,
+}
+
+impl LifeRule {
+    /**
+     Parse the B/S notation, one digit per neighbour count: "B3/S23" is Conway's game of life,
+     "B36/S23" is HighLife
+    */
+    pub fn parse(rule: &str) -> Option<LifeRule> {
+        let (birth, survival) = split_once(rule, "/")?;
+        let counts = |part: &str, prefix: char| -> Option<Vec<usize>> {
+            let digits = part.strip_prefix(prefix)?;
+            return digits
+                .chars()
+                .map(|c| c.to_digit(10).map(|d| d as usize))
+                .collect();
+        };
+        return Some(LifeRule {
+            birth: counts(birth, 'B')?,
+            survival: counts(survival, 'S')?,
+        });
+    }
+
+    /**
+     Whether the cell is alive at the next generation
+    */
+    pub fn next(&self, alive: bool, live_neighbours: usize) -> bool {
+        if alive {
+            return self.survival.contains(&live_neighbours);
+        }
+        return self.birth.contains(&live_neighbours);
+    }
+}
+
+impl fmt::Display for LifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let digits = |counts: &Vec<usize>| -> String {
+            return counts.iter().map(|count| count.to_string()).collect();
+        };
+        return write!(f, "B{}/S{}", digits(&self.birth), digits(&self.survival));
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum End {
+    FixedPoint,                            // the next generation is the same as the last one
+    Cycle { start: usize, length: usize }, // the last generation is the same as `start`
+    Limit,                                 // the maximum number of generations ran
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Outcome<P: Eq + Hash> {
+    pub alive: HashSet<P>, // live cells of the last generation
+    pub generations: usize,
+    pub end: End,
+}
+
+/**
+ A cellular automaton on cells identified by their position `P`: `neighbourhood` gives the
+ cells around a position (the ones whose live count feeds `rule`). It must be symmetric, `b` is
+ around `a` as many times as `a` is around `b`: live neighbours are counted by a single sweep
+ over the live cells, each one adding itself to the count of the cells around it.
+
+ With a `domain`, only its cells can ever be alive (a bounded grid, the seats of a room...).
+ Without one space is unbounded and only live cells and their neighbours are looked at, so a
+ rule with a birth on 0 neighbours does not spread to the whole space.
+*/
+pub struct Automaton<P, N> {
+    pub rule: LifeRule,
+    pub neighbourhood: N,
+    pub domain: Option<Vec<P>>,
+    /**
+     When a position stands for several cells (a class of cells in the same state), the number of
+     cells of its class. The neighbourhood then gives, for each cell around the class
+     representative, the representative of its class.
+    */
+    pub weight: Option<fn(&P) -> usize>,
+}
+
+impl<P, N> Automaton<P, N>
+where
+    P: Clone + Eq + Hash + Ord,
+    N: Fn(&P) -> Vec<P>,
+{
+    pub fn new(rule: LifeRule, neighbourhood: N) -> Automaton<P, N> {
+        return Automaton {
+            rule,
+            neighbourhood,
+            domain: None,
+            weight: None,
+        };
+    }
+
+    pub fn with_domain(rule: LifeRule, neighbourhood: N, domain: Vec<P>) -> Automaton<P, N> {
+        return Automaton {
+            rule,
+            neighbourhood,
+            domain: Some(domain),
+            weight: None,
+        };
+    }
+
+    /**
+     Next generation of the `alive` cells
+    */
+    pub fn step(&self, alive: &HashSet<P>) -> HashSet<P> {
+        // live neighbours of every cell next to a live one. With classes, a live class `a` adds
+        // weight(a) for each of its cells around `c`: that is weight(c) times the live cells of
+        // class `a` around any single cell of `c`.
+        let mut counts: HashMap<P, usize> = HashMap::new();
+        for position in alive {
+            let weight = self.weight.map_or(1, |weight| weight(position));
+            for neighbour in (self.neighbourhood)(position) {
+                *counts.entry(neighbour).or_insert(0) += weight;
+            }
+        }
+        let live_neighbours = |position: &P| -> usize {
+            let count = counts.get(position).copied().unwrap_or(0);
+            return match self.weight {
+                Some(weight) => count / weight(position),
+                None => count,
+            };
+        };
+        let candidates: Vec<P> = match &self.domain {
+            Some(domain) => domain.clone(),
+            None => counts
+                .keys()
+                .chain(
+                    alive
+                        .iter()
+                        .filter(|position| !counts.contains_key(position)),
+                )
+                .cloned()
+                .collect(),
+        };
+        return candidates
+            .into_iter()
+            .filter(|position| {
+                return self
+                    .rule
+                    .next(alive.contains(position), live_neighbours(position));
+            })
+            .collect();
+    }
+
+    /**
+     Every generation from the `alive` cells on, endless
+    */
+    pub fn generations(&self, alive: HashSet<P>) -> impl Iterator<Item = HashSet<P>> + '_ {
+        return std::iter::successors(Some(alive), move |current| Some(self.step(current)));
+    }
+
+    /**
+     Run from the `alive` cells until a fixed point, a cycle or `max_generations` (no limit when
+     None). `generations` counts the generations that changed something: the fixed point is
+     reached after it, a cycle is detected after it.
+    */
+    pub fn run(&self, alive: HashSet<P>, max_generations: Option<usize>) -> Outcome<P> {
+        // sorted live cells of every generation seen so far, to detect cycles
+        let mut history: HashMap<Vec<P>, usize> = HashMap::new();
+        let mut current = alive;
+        let mut generation = 0;
+        loop {
+            let mut key: Vec<P> = current.iter().cloned().collect();
+            key.sort_unstable();
+            if let Some(start) = history.insert(key, generation) {
+                let end = if generation - start == 1 {
+                    End::FixedPoint
+                } else {
+                    End::Cycle {
+                        start,
+                        length: generation - start,
+                    }
+                };
+                return Outcome {
+                    alive: current,
+                    generations: if end == End::FixedPoint {
+                        start
+                    } else {
+                        generation
+                    },
+                    end,
+                };
+            }
+            if Some(generation) == max_generations {
+                return Outcome {
+                    alive: current,
+                    generations: generation,
+                    end: End::Limit,
+                };
+            }
+            current = self.step(&current);
+            generation += 1;
+        }
+    }
+}
+
+/**
+ A cellular automaton on a fixed set of cells numbered from 0, each one with its neighbours
+ computed once: `neighbours[c]` lists the cells around `c` and must be symmetric like the
+ neighbourhood of `Automaton`.
+
+ The live neighbours count of every cell is kept from one generation to the next and only the
+ cells around a cell that changed are evaluated again, so a generation costs the number of changes
+ instead of the number of cells.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Network {
+    pub rule: LifeRule,
+    pub neighbours: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NetworkOutcome {
+    pub alive: Vec<bool>, // state of every cell at the last generation
+    pub generations: usize,
+    pub end: End,
+}
+
+/**
+ A network during a run: the state of each cell, its live neighbours count and the cells to
+ evaluate at the next step
+*/
+struct Evolution<'a> {
+    network: &'a Network,
+    alive: Vec<bool>,
+    live_neighbours: Vec<usize>,
+    pending: Vec<usize>,
+    is_pending: Vec<bool>,
+    // next state by live neighbours count, for a dead then for a live cell
+    transitions: [Vec<bool>; 2],
+    hash: u64, // xor of the `cell_hash` of the live cells
+}
+
+/**
+ Pseudo random value of a cell (splitmix64), to hash a generation from its changes only
+*/
+fn cell_hash(cell: usize) -> u64 {
+    let mut z = (cell as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    return z ^ (z >> 31);
+}
+
+impl<'a> Evolution<'a> {
+    fn new(network: &'a Network, alive: Vec<bool>) -> Evolution<'a> {
+        assert_eq!(alive.len(), network.neighbours.len(), "One state per cell");
+        let live_neighbours = network
+            .neighbours
+            .iter()
+            .map(|around| around.iter().filter(|n| alive[**n]).count())
+            .collect();
+        let max_count = network.neighbours.iter().map(Vec::len).max().unwrap_or(0);
+        let transitions = [false, true].map(|is_alive| {
+            return (0..=max_count)
+                .map(|count| network.rule.next(is_alive, count))
+                .collect();
+        });
+        let hash = (0..alive.len())
+            .filter(|c| alive[*c])
+            .fold(0, |hash, c| hash ^ cell_hash(c));
+        return Evolution {
+            network,
+            live_neighbours,
+            pending: (0..alive.len()).collect(),
+            is_pending: vec![true; alive.len()],
+            alive,
+            transitions,
+            hash,
+        };
+    }
+
+    /**
+     Run one generation, returns the number of cells that changed
+    */
+    fn step(&mut self) -> usize {
+        let changed: Vec<usize> = self
+            .pending
+            .iter()
+            .copied()
+            .filter(|c| {
+                let is_alive = self.alive[*c];
+                return self.transitions[is_alive as usize][self.live_neighbours[*c]] != is_alive;
+            })
+            .collect();
+        for c in self.pending.drain(..) {
+            self.is_pending[c] = false;
+        }
+        for c in &changed {
+            let c = *c;
+            self.alive[c] = !self.alive[c];
+            self.hash ^= cell_hash(c);
+            for neighbour in &self.network.neighbours[c] {
+                if self.alive[c] {
+                    self.live_neighbours[*neighbour] += 1;
+                } else {
+                    self.live_neighbours[*neighbour] -= 1;
+                }
+                if !self.is_pending[*neighbour] {
+                    self.is_pending[*neighbour] = true;
+                    self.pending.push(*neighbour);
+                }
+            }
+            if !self.is_pending[c] {
+                self.is_pending[c] = true;
+                self.pending.push(c);
+            }
+        }
+        return changed.len();
+    }
+}
+
+impl Network {
+    pub fn new(rule: LifeRule, neighbours: Vec<Vec<usize>>) -> Network {
+        return Network { rule, neighbours };
+    }
+
+    /**
+     Every generation from the `alive` cells on, endless
+    */
+    pub fn generations(&self, alive: Vec<bool>) -> impl Iterator<Item = Vec<bool>> + '_ {
+        let mut evolution = Evolution::new(self, alive);
+        let mut first = true;
+        return std::iter::from_fn(move || {
+            if !first {
+                evolution.step();
+            }
+            first = false;
+            return Some(evolution.alive.clone());
+        });
+    }
+
+    /**
+     Same as `Automaton::run`. Generations are remembered by their hash only, a generation with
+     the hash of an older one is compared with it by running the older one again.
+    */
+    pub fn run(&self, alive: Vec<bool>, max_generations: Option<usize>) -> NetworkOutcome {
+        let mut evolution = Evolution::new(self, alive.clone());
+        let mut history: HashMap<u64, usize> = HashMap::new();
+        let mut generation = 0;
+        loop {
+            let seen = history.insert(evolution.hash, generation);
+            if let Some(start) =
+                seen.filter(|start| self.state_at(&alive, *start) == evolution.alive)
+            {
+                return NetworkOutcome {
+                    alive: evolution.alive,
+                    generations: generation,
+                    end: End::Cycle {
+                        start,
+                        length: generation - start,
+                    },
+                };
+            }
+            if Some(generation) == max_generations {
+                return NetworkOutcome {
+                    alive: evolution.alive,
+                    generations: generation,
+                    end: End::Limit,
+                };
+            }
+            if evolution.step() == 0 {
+                return NetworkOutcome {
+                    alive: evolution.alive,
+                    generations: generation,
+                    end: End::FixedPoint,
+                };
+            }
+            generation += 1;
+        }
+    }
+
+    fn state_at(&self, alive: &[bool], generation: usize) -> Vec<bool> {
+        let mut evolution = Evolution::new(self, alive.to_vec());
+        for _ in 0..generation {
+            evolution.step();
+        }
+        return evolution.alive;
+    }
+}
+
+/**
+ The 8 cells around a position of the plane
+*/
+pub fn moore_neighbourhood(position: &(i64, i64)) -> Vec<(i64, i64)> {
+    let (x, y) = *position;
+    let mut res: Vec<(i64, i64)> = Vec::new();
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            if (dx, dy) != (0, 0) {
+                res.push((x + dx, y + dy));
+            }
+        }
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells(positions: &[(i64, i64)]) -> HashSet<(i64, i64)> {
+        return positions.iter().copied().collect();
+    }
+
+    #[test]
+    fn it_should_parse_rules() {
+        let high_life = LifeRule::parse("B36/S23").unwrap();
+        assert_eq!(high_life.birth, vec![3, 6]);
+        assert_eq!(high_life.survival, vec![2, 3]);
+        assert_eq!(high_life.to_string(), "B36/S23");
+        assert!(high_life.next(false, 6));
+        assert!(!high_life.next(true, 6));
+        assert_eq!(LifeRule::parse("B3S23"), None);
+        assert_eq!(LifeRule::parse("B3/Sx"), None);
+    }
+
+    #[test]
+    fn it_should_detect_fixed_points_and_cycles() {
+        let life = || Automaton::new(LifeRule::parse("B3/S23").unwrap(), moore_neighbourhood);
+
+        let block = cells(&[(0, 0), (1, 0), (0, 1), (1, 1)]);
+        let outcome = life().run(block.clone(), None);
+        assert_eq!((outcome.end, outcome.generations), (End::FixedPoint, 0));
+        assert_eq!(outcome.alive, block);
+
+        let blinker = cells(&[(0, 0), (1, 0), (2, 0)]);
+        let outcome = life().run(blinker.clone(), None);
+        let cycle = End::Cycle {
+            start: 0,
+            length: 2,
+        };
+        assert_eq!((outcome.end, outcome.generations), (cycle, 2));
+        assert_eq!(life().step(&blinker), cells(&[(1, -1), (1, 0), (1, 1)]));
+
+        let outcome = life().run(blinker, Some(1));
+        assert_eq!((outcome.end, outcome.generations), (End::Limit, 1));
+
+        // a lone cell dies, then nothing changes anymore
+        let outcome = life().run(cells(&[(5, 5)]), None);
+        assert_eq!((outcome.end, outcome.generations), (End::FixedPoint, 1));
+        assert!(outcome.alive.is_empty());
+    }
+
+    #[test]
+    fn it_should_run_networks_like_automatons() {
+        // a blinker in a 5x5 torus
+        let neighbours: Vec<Vec<usize>> = (0..25)
+            .map(|c| {
+                return moore_neighbourhood(&(c % 5, c / 5))
+                    .iter()
+                    .map(|(x, y)| (((y + 5) % 5) * 5 + (x + 5) % 5) as usize)
+                    .collect();
+            })
+            .collect();
+        let network = Network::new(LifeRule::parse("B3/S23").unwrap(), neighbours);
+        let mut blinker = vec![false; 25];
+        for c in [11, 12, 13] {
+            blinker[c] = true;
+        }
+        let generations: Vec<Vec<bool>> = network.generations(blinker.clone()).take(3).collect();
+        let vertical: Vec<usize> = (0..25).filter(|c| generations[1][*c]).collect();
+        assert_eq!(vertical, vec![7, 12, 17]);
+        assert_eq!(generations[2], blinker);
+        let outcome = network.run(blinker, None);
+        let cycle = End::Cycle {
+            start: 0,
+            length: 2,
+        };
+        assert_eq!((outcome.end, outcome.generations), (cycle, 2));
+
+        let outcome = network.run(vec![false; 25], None);
+        assert_eq!((outcome.end, outcome.generations), (End::FixedPoint, 0));
+    }
+
+    #[test]
+    fn it_should_only_grow_inside_domain() {
+        let domain = vec![(0, 0), (1, 0), (3, 0)];
+        let rule = LifeRule::parse("B0/S").unwrap();
+        let automaton = Automaton::with_domain(rule, moore_neighbourhood, domain);
+        assert_eq!(automaton.step(&HashSet::new()).len(), 3);
+        let outcome = automaton.run(HashSet::new(), None);
+        let cycle = End::Cycle {
+            start: 0,
+            length: 2,
+        };
+        assert_eq!(outcome.end, cycle);
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::Grid;
+
+/**
+ Frames of the same size to encode as a looping animated GIF, each cell of a frame being an
+ index in `palette`
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Animation {
+    pub palette: Vec<[u8; 3]>, // RGB colors, at most 256
+    pub frames: Vec<Grid<u8>>,
+    pub delay: u16, // between two frames, in hundredths of a second
+    pub scale: u16, // size in pixels of a cell
+}
+
+/**
+ Frames too large for a GIF: its width and height are 16 bits numbers
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GifSizeError {
+    pub width: usize, // in pixels, after scaling
+    pub height: usize,
+}
+
+impl fmt::Display for GifSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "{}x{} pixels is larger than the 65535x65535 of a GIF",
+            self.width, self.height
+        );
+    }
+}
+
+impl std::error::Error for GifSizeError {}
+
+impl Animation {
+    pub fn new(palette: Vec<[u8; 3]>, delay: u16, scale: u16) -> Animation {
+        assert!(
+            !palette.is_empty() && palette.len() <= 256,
+            "A GIF palette has 1 to 256 colors"
+        );
+        return Animation {
+            palette,
+            frames: Vec::new(),
+            delay,
+            scale,
+        };
+    }
+
+    pub fn push(&mut self, frame: Grid<u8>) {
+        if let Some(first) = self.frames.first() {
+            assert_eq!(
+                (first.width(), first.height()),
+                (frame.width(), frame.height()),
+                "Every frame should have the same size"
+            );
+        }
+        self.frames.push(frame);
+    }
+
+    /**
+     The GIF89a file: a global color table, a loop forever extension then every frame. Fails
+     when the scaled frames are wider or higher than 65535 pixels.
+    */
+    pub fn to_gif(&self) -> Result<Vec<u8>, GifSizeError> {
+        let scale = self.scale.max(1) as usize;
+        let (width, height) = self
+            .frames
+            .first()
+            .map(|frame| {
+                return (
+                    frame.width().saturating_mul(scale),
+                    frame.height().saturating_mul(scale),
+                );
+            })
+            .unwrap_or((0, 0));
+        let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+            (Ok(w), Ok(h)) => (w, h),
+            _ => return Err(GifSizeError { width, height }),
+        };
+        // the color table holds 2^(size + 1) colors
+        let size = (0..8)
+            .find(|size| 2 << size >= self.palette.len())
+            .unwrap_or(7);
+
+        let mut res: Vec<u8> = b"GIF89a".to_vec();
+        res.extend(&width.to_le_bytes());
+        res.extend(&height.to_le_bytes());
+        res.push(0xF0 | size as u8); // global color table, 8 bits per primary color
+        res.push(0); // background color index
+        res.push(0); // no aspect ratio
+        for index in 0..(2 << size) {
+            res.extend(self.palette.get(index).unwrap_or(&[0, 0, 0]));
+        }
+        res.extend(b"\x21\xFF\x0BNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+        for frame in &self.frames {
+            res.extend(&[0x21, 0xF9, 0x04, 0x00]);
+            res.extend(&self.delay.to_le_bytes());
+            res.extend(&[0x00, 0x00]);
+            res.push(0x2C);
+            res.extend(&[0, 0, 0, 0]);
+            res.extend(&width.to_le_bytes());
+            res.extend(&height.to_le_bytes());
+            res.push(0); // no local color table, not interlaced
+            let min_code_size = (size + 1).max(2);
+            res.push(min_code_size as u8);
+            let data = lzw_encode(&scaled_pixels(frame, scale), min_code_size);
+            for block in data.chunks(255) {
+                res.push(block.len() as u8);
+                res.extend(block);
+            }
+            res.push(0);
+        }
+        res.push(0x3B);
+        return Ok(res);
+    }
+}
+
+fn scaled_pixels(frame: &Grid<u8>, scale: usize) -> Vec<u8> {
+    let mut res: Vec<u8> = Vec::with_capacity(frame.cells().len() * scale * scale);
+    for row in frame.cells().chunks(frame.width().max(1)) {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|color| std::iter::repeat_n(*color, scale))
+            .collect();
+        for _ in 0..scale {
+            res.extend(&line);
+        }
+    }
+    return res;
+}
+
+/**
+ Variable-length LZW codes of the GIF image data, packed from the least significant bit
+*/
+fn lzw_encode(pixels: &[u8], min_code_size: usize) -> Vec<u8> {
+    let clear_code: u16 = 1 << min_code_size;
+    let end_code: u16 = clear_code + 1;
+    let mut res: Vec<u8> = Vec::new();
+    let mut buffer: u32 = 0;
+    let mut buffer_bits: usize = 0;
+    let mut emit = |code: u16, code_size: usize| {
+        buffer |= (code as u32) << buffer_bits;
+        buffer_bits += code_size;
+        while buffer_bits >= 8 {
+            res.push(buffer as u8);
+            buffer >>= 8;
+            buffer_bits -= 8;
+        }
+    };
+
+    let mut code_size = min_code_size + 1;
+    let mut next_code = end_code + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    emit(clear_code, code_size);
+    let mut prefix: Option<u16> = None;
+    for pixel in pixels {
+        let current = match prefix {
+            None => {
+                prefix = Some(*pixel as u16);
+                continue;
+            }
+            Some(current) => current,
+        };
+        if let Some(code) = table.get(&(current, *pixel)) {
+            prefix = Some(*code);
+            continue;
+        }
+        emit(current, code_size);
+        if next_code == 4096 {
+            // the table is full: start a new one
+            emit(clear_code, code_size);
+            table.clear();
+            code_size = min_code_size + 1;
+            next_code = end_code + 1;
+        } else {
+            if next_code >= 1 << code_size {
+                code_size += 1;
+            }
+            table.insert((current, *pixel), next_code);
+            next_code += 1;
+        }
+        prefix = Some(*pixel as u16);
+    }
+    if let Some(current) = prefix {
+        emit(current, code_size);
+    }
+    emit(end_code, code_size);
+    if buffer_bits > 0 {
+        res.push(buffer as u8);
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_lzw_encode_pixels() {
+        // the 10x10 sample image of the GIF format walkthroughs
+        let rows = [
+            "1111122222",
+            "1111122222",
+            "1111122222",
+            "1110000222",
+            "1110000222",
+            "2220000111",
+            "2220000111",
+            "2222211111",
+            "2222211111",
+            "2222211111",
+        ];
+        let pixels: Vec<u8> = rows
+            .iter()
+            .flat_map(|row| row.bytes().map(|b| b - b'0'))
+            .collect();
+        assert_eq!(
+            lzw_encode(&pixels, 2),
+            vec![
+                0x8C, 0x2D, 0x99, 0x87, 0x2A, 0x1C, 0xDC, 0x33, 0xA0, 0x02, 0x75, 0xEC, 0x95, 0xFA,
+                0xA8, 0xDE, 0x60, 0x8C, 0x04, 0x91, 0x4C, 0x01
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_write_animated_gif() {
+        let mut animation = Animation::new(vec![[0, 0, 0], [255, 255, 255], [255, 0, 0]], 50, 2);
+        animation.push(Grid::new(2, 1, vec![0, 1]));
+        animation.push(Grid::new(2, 1, vec![2, 1]));
+        let gif = animation.to_gif().unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[4, 0, 2, 0]); // 2x1 cells of 2x2 pixels
+        assert_eq!(gif[10], 0xF1); // 4 colors in the table
+        assert_eq!(gif.last(), Some(&0x3B));
+    }
+
+    #[test]
+    fn it_should_reject_frames_larger_than_a_gif() {
+        let mut animation = Animation::new(vec![[0, 0, 0]], 50, 2);
+        animation.push(Grid::new(32768, 1, vec![0; 32768]));
+        assert_eq!(
+            animation.to_gif(),
+            Err(GifSizeError {
+                width: 65536,
+                height: 2
+            })
+        );
+    }
+}
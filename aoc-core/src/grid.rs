@@ -0,0 +1,297 @@
+use std::ops::{Index, IndexMut};
+
+use crate::{lines, ParseError};
+
+/**
+ Directions to the 4 orthogonal neighbours, as (dx, dy) with y growing downwards
+*/
+pub const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/**
+ Directions to the 8 neighbours (orthogonal and diagonal), row by row from the top left one
+*/
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/**
+ A rectangular map of cells stored row by row in a single buffer, `(x, y)` is (column, row) with
+ `(0, 0)` the top left cell.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /**
+     `cells` are given row by row, there must be `width * height` of them
+    */
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Grid size mismatch");
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+
+    /**
+     Parse a character map, one row per non-empty line. `cell` maps each character, the ones it
+     rejects (None) are reported with `message`, and every row must have the same length.
+    */
+    pub fn parse<F>(day: u8, input: &str, cell: F, message: &str) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let mut width: Option<usize> = None;
+        let mut height: usize = 0;
+        let mut cells: Vec<T> = Vec::new();
+        for line in lines(input) {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let fragment = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::at(day, input, fragment, message));
+                    }
+                }
+            }
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(
+                    day,
+                    input,
+                    line,
+                    "Every row should have the same length",
+                ));
+            }
+            height += 1;
+        }
+        return Ok(Grid::new(width.unwrap_or(0), height, cells));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /**
+     Every cell, row by row
+    */
+    pub fn cells(&self) -> &[T] {
+        return &self.cells;
+    }
+
+    /**
+     Every position, row by row
+    */
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..self.cells.len()).map(move |index| (index % width, index / width));
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return self.cells.get(y * self.width + x);
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        return self.cells.get_mut(y * self.width + x);
+    }
+
+    /**
+     Like `get` on a grid repeating itself forever along `axis`: the coordinate on that axis is
+     taken modulo the grid size, the other one must still be inside the grid
+    */
+    pub fn get_wrapping(&self, x: usize, y: usize, axis: Axis) -> Option<&T> {
+        if self.cells.is_empty() {
+            return None;
+        }
+        return match axis {
+            Axis::X => self.get(x % self.width, y),
+            Axis::Y => self.get(x, y % self.height),
+        };
+    }
+
+    /**
+     Position one step away from `(x, y)` in `direction`, None outside of the grid
+    */
+    pub fn step(&self, x: usize, y: usize, direction: (isize, isize)) -> Option<(usize, usize)> {
+        let next_x = x as isize + direction.0;
+        let next_y = y as isize + direction.1;
+        if next_x < 0
+            || next_y < 0
+            || next_x as usize >= self.width
+            || next_y as usize >= self.height
+        {
+            return None;
+        }
+        return Some((next_x as usize, next_y as usize));
+    }
+
+    /**
+     Positions of the orthogonal neighbours of `(x, y)` inside the grid
+    */
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBOURS4
+            .iter()
+            .filter_map(move |direction| self.step(x, y, *direction));
+    }
+
+    /**
+     Positions of the orthogonal and diagonal neighbours of `(x, y)` inside the grid
+    */
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return NEIGHBOURS8
+            .iter()
+            .filter_map(move |direction| self.step(x, y, *direction));
+    }
+
+    /**
+     Positions seen from `(x, y)` looking in `direction`, nearest first and `(x, y)` excluded,
+     until the edge of the grid
+    */
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut current = Some((x, y));
+        return std::iter::from_fn(move || {
+            let (x, y) = current?;
+            current = self.step(x, y, direction);
+            return current;
+        });
+    }
+
+    /**
+     A grid of the same size with `f` applied to every cell, `f` also gets the cell position
+    */
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut((usize, usize), &T) -> U,
+    {
+        let cells = self
+            .positions()
+            .zip(self.cells.iter())
+            .map(|(position, cell)| f(position, cell))
+            .collect();
+        return Grid::new(self.width, self.height, cells);
+    }
+
+    /**
+     Back to a character map, each row ends with a new line
+    */
+    pub fn render<F>(&self, cell: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let mut res = String::with_capacity((self.width + 1) * self.height);
+        for row in self.cells.chunks(self.width.max(1)) {
+            res.extend(row.iter().map(&cell));
+            res.push('\n');
+        }
+        return res;
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        return self.get(x, y).expect("Position outside of the grid");
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        return self.get_mut(x, y).expect("Position outside of the grid");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cell(c: char) -> Option<bool> {
+        return match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+    }
+
+    fn render(grid: &Grid<bool>) -> String {
+        return grid.render(|c| if *c { '#' } else { '.' });
+    }
+
+    #[test]
+    fn it_should_parse_and_render() {
+        let grid = Grid::parse(1, "#..\n.#.\n", cell, "Invalid cell").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[(1, 1)]);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(render(&grid), "#..\n.#.\n");
+
+        let error = Grid::parse(1, "#..\n.x.\n", cell, "Invalid cell").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "Invalid cell");
+        let error = Grid::parse(1, "#..\n.#\n", cell, "Invalid cell").unwrap_err();
+        assert_eq!((error.line, error.fragment.as_str()), (2, ".#"));
+    }
+
+    #[test]
+    fn it_should_iterate_neighbours() {
+        let grid = Grid::new(3, 3, (0..9).collect::<Vec<u8>>());
+        let around = |positions: Vec<(usize, usize)>| -> Vec<u8> {
+            return positions.into_iter().map(|p| grid[p]).collect();
+        };
+        assert_eq!(around(grid.neighbours4(1, 1).collect()), vec![1, 5, 7, 3]);
+        assert_eq!(around(grid.neighbours4(0, 0).collect()), vec![1, 3]);
+        assert_eq!(
+            around(grid.neighbours8(1, 1).collect()),
+            vec![0, 1, 2, 3, 5, 6, 7, 8]
+        );
+        assert_eq!(around(grid.neighbours8(2, 2).collect()), vec![4, 5, 7]);
+    }
+
+    #[test]
+    fn it_should_cast_rays_and_wrap() {
+        let grid = Grid::new(4, 2, (0..8).collect::<Vec<u8>>());
+        assert_eq!(
+            grid.ray(0, 0, (1, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (2, 0), (3, 0)]
+        );
+        assert_eq!(grid.ray(0, 0, (1, 1)).collect::<Vec<_>>(), vec![(1, 1)]);
+        assert_eq!(grid.ray(0, 0, (-1, 0)).count(), 0);
+        assert_eq!(grid.get_wrapping(9, 1, Axis::X), Some(&5));
+        assert_eq!(grid.get_wrapping(1, 3, Axis::Y), Some(&5));
+        assert_eq!(grid.get_wrapping(1, 3, Axis::X), None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::fs::File;
+use std::io::prelude::*;
+use std::str::FromStr;
+
+mod automaton;
+mod gif;
+mod grid;
+mod parse_error;
+mod solution;
+mod vec2;
+
+pub use automaton::{
+    moore_neighbourhood, Automaton, End, LifeRule, Network, NetworkOutcome, Outcome,
+};
+pub use gif::{Animation, GifSizeError};
+pub use grid::{Axis, Grid, NEIGHBOURS4, NEIGHBOURS8};
+pub use parse_error::ParseError;
+pub use solution::{Answer, Solution};
+pub use vec2::{quarter_turns, AngleError, Heading, Vec2};
+
+/// Read the whole content of `file_name`.
+pub fn read_input(file_name: &str) -> std::io::Result<String> {
+    let mut file = File::open(file_name)?;
+    let mut contents = String::new();
+    file.read_to_string(&mut contents)?;
+    return Ok(contents);
+}
+
+/// Trimmed, non-empty lines of `input`.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    return input
+        .split('\n')
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty());
+}
+
+/// Blocks of lines separated by at least one blank line, each block trimmed.
+///
+/// "a\nb\n\nc\n" => ["a\nb", "c"]
+pub fn groups(input: &str) -> Vec<&str> {
+    let mut res: Vec<&str> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end: usize = 0;
+    let mut offset: usize = 0;
+    for raw_line in input.split('\n') {
+        let line_end = offset + raw_line.len();
+        if raw_line.trim().is_empty() {
+            if let Some(s) = start {
+                res.push(input[s..end].trim());
+                start = None;
+            }
+        } else {
+            if start.is_none() {
+                start = Some(offset);
+            }
+            end = line_end;
+        }
+        offset = line_end + 1;
+    }
+    if let Some(s) = start {
+        res.push(input[s..end].trim());
+    }
+    return res;
+}
+
+/// Parse every number of `input`, numbers being separated by commas and/or whitespaces.
+/// The first invalid number is reported as a `day` error.
+///
+/// "1\n2\n3" => [1, 2, 3]
+/// "13,16,0" => [13, 16, 0]
+pub fn parse_numbers<T: FromStr>(day: u8, input: &str) -> Result<Vec<T>, ParseError> {
+    return input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|n| !n.is_empty())
+        .map(|n| {
+            n.parse::<T>()
+                .map_err(|_| ParseError::at(day, input, n, "Cannot parse as a number"))
+        })
+        .collect();
+}
+
+/// Split `s` on the first occurrence of `pattern`, the pattern itself is excluded.
+///
+/// split_once("a bags contain b", " bags contain") => Some(("a", " b"))
+pub fn split_once<'a>(s: &'a str, pattern: &str) -> Option<(&'a str, &'a str)> {
+    return s
+        .find(pattern)
+        .map(|index| (&s[..index], &s[index + pattern.len()..]));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_iterate_non_empty_lines() {
+        let actual: Vec<&str> = lines("nop +0\n  acc +1  \n\njmp +4\n").collect();
+        assert_eq!(actual, vec!["nop +0", "acc +1", "jmp +4"]);
+    }
+
+    #[test]
+    fn it_should_split_groups() {
+        assert_eq!(
+            groups("abc\n\na\nb\nc\n\n\nab\nac\n"),
+            vec!["abc", "a\nb\nc", "ab\nac"]
+        );
+        assert_eq!(groups("\n\na b\n  \nc"), vec!["a b", "c"]);
+        assert_eq!(groups(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn it_should_parse_numbers() {
+        assert_eq!(
+            parse_numbers::<u32>(1, "1721\n979\n366\n"),
+            Ok(vec![1721, 979, 366])
+        );
+        assert_eq!(
+            parse_numbers::<u64>(15, "13,16,0,12"),
+            Ok(vec![13, 16, 0, 12])
+        );
+        assert_eq!(
+            parse_numbers::<u64>(9, "1\n2 foo\n").map_err(|err| (
+                err.line,
+                err.column,
+                err.fragment
+            )),
+            Err((2, 3, "foo".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_split_once() {
+        assert_eq!(
+            split_once(
+                "clear aqua bags contain 2 plaid green bags.",
+                " bags contain"
+            ),
+            Some(("clear aqua", " 2 plaid green bags."))
+        );
+        assert_eq!(split_once("1 a b c", " "), Some(("1", "a b c")));
+        assert_eq!(split_once("abc", " "), None);
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+/**
+ A puzzle input that cannot be parsed.
+
+ `line` and `column` are 1-based and point to the start of `fragment`, the part of the input
+ that was rejected.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub day: u8,
+    pub line: usize,
+    pub column: usize,
+    pub fragment: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /**
+     Reject `fragment`, a slice borrowed from `input` (a line, a group, a token...): its
+     position in `input` gives the line and the column.
+
+     A fragment that does not come from `input` is searched in it instead, and is located at
+     the end of the input when it cannot be found (a missing line for example).
+    */
+    pub fn at(day: u8, input: &str, fragment: &str, message: &str) -> ParseError {
+        let offset = offset_of(input, fragment);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+        return ParseError {
+            day,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            fragment: fragment.to_string(),
+            message: message.to_string(),
+        };
+    }
+}
+
+fn offset_of(input: &str, fragment: &str) -> usize {
+    let input_start = input.as_ptr() as usize;
+    let fragment_start = fragment.as_ptr() as usize;
+    if fragment_start >= input_start && fragment_start + fragment.len() <= input_start + input.len()
+    {
+        return fragment_start - input_start;
+    }
+    return input.find(fragment).unwrap_or(input.len());
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "Day {:02}, line {}, column {}: {}: {:?}",
+            self.day, self.line, self.column, self.message, self.fragment
+        );
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_locate_a_borrowed_fragment() {
+        let input = "nop +0\nacc +1\njmp +4\n";
+        let line = input.split('\n').nth(1).unwrap();
+        let error = ParseError::at(8, input, &line[4..], "Invalid argument");
+        assert_eq!(
+            error,
+            ParseError {
+                day: 8,
+                line: 2,
+                column: 5,
+                fragment: "+1".to_string(),
+                message: "Invalid argument".to_string(),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Day 08, line 2, column 5: Invalid argument: \"+1\""
+        );
+    }
+
+    #[test]
+    fn it_should_locate_a_copied_fragment() {
+        let input = "L.LL\nLLxL\n";
+        let error = ParseError::at(11, input, "x", "Invalid seat");
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = ParseError::at(11, input, "", "Missing row");
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = ParseError::at(11, "L.LL", "#", "Missing row");
+        assert_eq!((error.line, error.column), (1, 5));
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt::Display;
+
+use crate::ParseError;
+
+/**
+ A day puzzle: the input is parsed once, then shared by both parts.
+
+ Answers are returned instead of printed so they can be tested, compared and
+ displayed the same way for every day.
+*/
+pub trait Solution {
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/**
+ What a part returns: the text of the answer, or why the input has none.
+
+ A part that may find no answer returns a `Result`, its error is reported by
+ the runner instead of a panic.
+*/
+pub trait Answer {
+    fn render(&self) -> Result<String, String>;
+}
+
+macro_rules! display_answer {
+    ($($answer:ty),*) => {
+        $(
+            impl Answer for $answer {
+                fn render(&self) -> Result<String, String> {
+                    return Ok(self.to_string());
+                }
+            }
+        )*
+    };
+}
+
+display_answer!(u32, u64, u128, usize, i64, String);
+
+impl<T: Answer, E: Display> Answer for Result<T, E> {
+    fn render(&self) -> Result<String, String> {
+        return match self {
+            Ok(answer) => answer.render(),
+            Err(err) => Err(err.to_string()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_answers_and_missing_answers() {
+        assert_eq!(42u64.render(), Ok("42".to_string()));
+        assert_eq!(Ok::<i64, String>(-3).render(), Ok("-3".to_string()));
+        assert_eq!(
+            Err::<u32, &str>("no solution").render(),
+            Err("no solution".to_string())
+        );
+    }
+}
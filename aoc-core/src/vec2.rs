@@ -0,0 +1,223 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+use crate::Axis;
+
+/**
+ A 2D integer vector with x growing to the east and y growing to the north, so positive
+ rotations are counterclockwise
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Vec2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/**
+ A turn that is not a multiple of 90 degrees
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AngleError {
+    pub degrees: i64,
+}
+
+impl fmt::Display for AngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} degrees is not a right angle turn", self.degrees);
+    }
+}
+
+impl std::error::Error for AngleError {}
+
+/**
+ Number of counterclockwise quarter turns in `degrees`, from 0 to 3
+*/
+pub fn quarter_turns(degrees: i64) -> Result<u8, AngleError> {
+    if degrees % 90 != 0 {
+        return Err(AngleError { degrees });
+    }
+    return Ok((degrees / 90).rem_euclid(4) as u8);
+}
+
+impl Vec2 {
+    pub const ZERO: Vec2 = Vec2 { x: 0, y: 0 };
+
+    pub fn new(x: i64, y: i64) -> Vec2 {
+        return Vec2 { x, y };
+    }
+
+    /**
+     Rotated around the origin by `quarters` counterclockwise quarter turns (negative ones turn
+     clockwise)
+    */
+    pub fn rotate_quarters(self, quarters: i64) -> Vec2 {
+        return match quarters.rem_euclid(4) {
+            0 => self,
+            1 => Vec2::new(-self.y, self.x),
+            2 => Vec2::new(-self.x, -self.y),
+            _ => Vec2::new(self.y, -self.x),
+        };
+    }
+
+    /**
+     Rotated around the origin by `degrees` counterclockwise, which must be a multiple of 90
+    */
+    pub fn rotate(self, degrees: i64) -> Result<Vec2, AngleError> {
+        return quarter_turns(degrees).map(|quarters| self.rotate_quarters(quarters as i64));
+    }
+
+    /**
+     Mirror image across the `axis` line: `Axis::X` negates y, `Axis::Y` negates x
+    */
+    pub fn reflect(self, axis: Axis) -> Vec2 {
+        return match axis {
+            Axis::X => Vec2::new(self.x, -self.y),
+            Axis::Y => Vec2::new(-self.x, self.y),
+        };
+    }
+
+    /**
+     Distance from the origin moving along the axes only
+    */
+    pub fn manhattan(self) -> i64 {
+        return self.x.abs() + self.y.abs();
+    }
+
+    /**
+     Distance from the origin moving along the axes and the diagonals (a king on a chessboard)
+    */
+    pub fn chebyshev(self) -> i64 {
+        return self.x.abs().max(self.y.abs());
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    fn add(self, other: Vec2) -> Vec2 {
+        return Vec2::new(self.x + other.x, self.y + other.y);
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Vec2) -> Vec2 {
+        return Vec2::new(self.x - other.x, self.y - other.y);
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Vec2 {
+        return Vec2::new(-self.x, -self.y);
+    }
+}
+
+impl Mul<i64> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, factor: i64) -> Vec2 {
+        return Vec2::new(self.x * factor, self.y * factor);
+    }
+}
+
+/**
+ One of the 4 cardinal directions
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /**
+     Unit vector pointing towards the heading
+    */
+    pub fn vector(self) -> Vec2 {
+        return match self {
+            Heading::North => Vec2::new(0, 1),
+            Heading::East => Vec2::new(1, 0),
+            Heading::South => Vec2::new(0, -1),
+            Heading::West => Vec2::new(-1, 0),
+        };
+    }
+
+    fn from_vector(vector: Vec2) -> Heading {
+        return match (vector.x, vector.y) {
+            (0, 1) => Heading::North,
+            (1, 0) => Heading::East,
+            (0, -1) => Heading::South,
+            _ => Heading::West,
+        };
+    }
+
+    /**
+     Heading after `quarters` counterclockwise quarter turns (negative ones turn clockwise)
+    */
+    pub fn rotate_quarters(self, quarters: i64) -> Heading {
+        return Heading::from_vector(self.vector().rotate_quarters(quarters));
+    }
+
+    /**
+     Heading after turning `degrees` counterclockwise, which must be a multiple of 90
+    */
+    pub fn rotate(self, degrees: i64) -> Result<Heading, AngleError> {
+        return quarter_turns(degrees).map(|quarters| self.rotate_quarters(quarters as i64));
+    }
+
+    /**
+     Mirror image across the `axis` line, see `Vec2::reflect`
+    */
+    pub fn reflect(self, axis: Axis) -> Heading {
+        return Heading::from_vector(self.vector().reflect(axis));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_rotate_by_right_angles() {
+        let v = Vec2::new(10, 4);
+        assert_eq!(v.rotate(90), Ok(Vec2::new(-4, 10)));
+        assert_eq!(v.rotate(-90), Ok(Vec2::new(4, -10)));
+        assert_eq!(v.rotate(180), Ok(Vec2::new(-10, -4)));
+        assert_eq!(v.rotate(-270), v.rotate(90));
+        assert_eq!(v.rotate(720), Ok(v));
+        assert_eq!(v.rotate(45), Err(AngleError { degrees: 45 }));
+        assert_eq!(
+            AngleError { degrees: 45 }.to_string(),
+            "45 degrees is not a right angle turn"
+        );
+
+        assert_eq!(Heading::East.rotate(90), Ok(Heading::North));
+        assert_eq!(Heading::North.rotate_quarters(-1), Heading::East);
+        assert_eq!(Heading::West.rotate(-180), Ok(Heading::East));
+        assert!(Heading::West.rotate(100).is_err());
+    }
+
+    #[test]
+    fn it_should_reflect_and_measure() {
+        let v = Vec2::new(17, -8);
+        assert_eq!(v.reflect(Axis::X), Vec2::new(17, 8));
+        assert_eq!(v.reflect(Axis::Y), Vec2::new(-17, -8));
+        assert_eq!(Heading::North.reflect(Axis::X), Heading::South);
+        assert_eq!(Heading::North.reflect(Axis::Y), Heading::North);
+        assert_eq!(v.manhattan(), 25);
+        assert_eq!(v.chebyshev(), 17);
+        assert_eq!((v - Vec2::new(20, 2)).manhattan(), 13);
+        assert_eq!(Vec2::ZERO + Heading::South.vector() * 3, Vec2::new(0, -3));
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_core::{lines, read_input};
+
+use crate::days;
+
+pub const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt");
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub input: String, // file name in the day directory
+    pub expected: String,
+    pub slow: bool,
+}
+
+/**
+ Parse the answers manifest, one answer per line, `#` starts a comment:
+
+ `<day> <part> <input file> <expected answer> [slow]`
+*/
+pub fn parse_manifest(manifest: &str) -> Result<Vec<KnownAnswer>, String> {
+    return lines(manifest)
+        .filter(|line| !line.starts_with('#'))
+        .map(|line| {
+            let split: Vec<&str> = line.split_whitespace().collect();
+            let slow = match split.get(4) {
+                None => false,
+                Some(&"slow") => true,
+                Some(other) => return Err(format!("Unknown flag {:?} in {:?}", other, line)),
+            };
+            if split.len() < 4 || split.len() > 5 {
+                return Err(format!(
+                    "Should be \"<day> <part> <input> <expected> [slow]\": {:?}",
+                    line
+                ));
+            }
+            let day = split[0]
+                .parse::<u8>()
+                .map_err(|_| format!("Invalid day in {:?}", line))?;
+            let part = match split[1] {
+                "1" => 1,
+                "2" => 2,
+                _ => return Err(format!("Invalid part in {:?}", line)),
+            };
+            return Ok(KnownAnswer {
+                day,
+                part,
+                input: split[2].to_string(),
+                expected: split[3].to_string(),
+                slow,
+            });
+        })
+        .collect();
+}
+
+/**
+ Solve the known answer part on its input, the error describes the mismatch
+*/
+pub fn check(known: &KnownAnswer) -> Result<(), String> {
+    let day =
+        days::find(known.day).ok_or_else(|| format!("Day {} is not solved yet", known.day))?;
+    let path = format!("{}/{}", day.dir, known.input);
+    let input = read_input(&path).map_err(|err| format!("Cannot read {}: {}", path, err))?;
+    let answers = (day.solve)(&input, Some(known.part))?;
+    return match answers.first() {
+        Some((_, actual)) if *actual == known.expected => Ok(()),
+        Some((_, actual)) => Err(format!("expected {}, got {}", known.expected, actual)),
+        None => Err("no answer".to_string()),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_all(slow: bool) {
+        let manifest = read_input(MANIFEST).unwrap();
+        let failures: Vec<String> = parse_manifest(&manifest)
+            .unwrap()
+            .iter()
+            .filter(|known| known.slow == slow)
+            .filter_map(|known| {
+                check(known).err().map(|err| {
+                    format!(
+                        "Day {:02} part {} ({}): {}",
+                        known.day, known.part, known.input, err
+                    )
+                })
+            })
+            .collect();
+        assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn it_should_parse_manifest() {
+        assert_eq!(
+            parse_manifest(
+                "# day part input answer\n11 2 input2.txt 26\n15 2 input1.txt 2424 slow\n"
+            ),
+            Ok(vec![
+                KnownAnswer {
+                    day: 11,
+                    part: 2,
+                    input: "input2.txt".to_string(),
+                    expected: "26".to_string(),
+                    slow: false,
+                },
+                KnownAnswer {
+                    day: 15,
+                    part: 2,
+                    input: "input1.txt".to_string(),
+                    expected: "2424".to_string(),
+                    slow: true,
+                },
+            ])
+        );
+        assert!(parse_manifest("11 3 input2.txt 26").is_err());
+        assert!(parse_manifest("11 2 input2.txt").is_err());
+        assert!(parse_manifest("11 2 input2.txt 26 fast").is_err());
+    }
+
+    #[test]
+    fn it_should_match_known_answers() {
+        check_all(false);
+    }
+
+    #[test]
+    #[ignore]
+    fn it_should_match_slow_known_answers() {
+        check_all(true);
+    }
+}
@@ -0,0 +1,170 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_core::Solution;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+/**
+ Timings of one step ("parse", "part1" or "part2") of a day over every iteration
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Timing {
+    pub day: u8,
+    pub step: &'static str,
+    pub iterations: u32,
+    pub min: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    fn new(day: u8, step: &'static str, durations: &Vec<Duration>) -> Timing {
+        let total: Duration = durations.iter().sum();
+        return Timing {
+            day,
+            step,
+            iterations: durations.len() as u32,
+            min: durations.iter().min().copied().unwrap_or_default(),
+            mean: total / (durations.len().max(1) as u32),
+            max: durations.iter().max().copied().unwrap_or_default(),
+        };
+    }
+}
+
+/**
+ Run `f` `iterations` times (at least once), return its last result and the duration of each run
+*/
+fn time<T>(iterations: u32, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+    let mut durations: Vec<Duration> = Vec::new();
+    loop {
+        let start = Instant::now();
+        let res = black_box(f());
+        durations.push(start.elapsed());
+        if durations.len() as u32 >= iterations {
+            return (res, durations);
+        }
+    }
+}
+
+/**
+ Time parse, part1 and part2 separately, the parts reuse the last parsed input
+*/
+pub fn measure<S: Solution>(day: u8, input: &str, iterations: u32) -> Result<Vec<Timing>, String> {
+    let (parsed, parse_durations) = time(iterations, || S::parse(black_box(input)));
+    let data = parsed.map_err(|err| err.to_string())?;
+    let (_, part1_durations) = time(iterations, || S::part1(black_box(&data)));
+    let (_, part2_durations) = time(iterations, || S::part2(black_box(&data)));
+    return Ok(vec![
+        Timing::new(day, "parse", &parse_durations),
+        Timing::new(day, "part1", &part1_durations),
+        Timing::new(day, "part2", &part2_durations),
+    ]);
+}
+
+pub fn report(timings: &Vec<Timing>, format: ReportFormat) -> String {
+    let mut res = String::new();
+    match format {
+        ReportFormat::Text => {
+            for t in timings {
+                res += &format!(
+                    "Day {:02} {}: mean {:?} (min {:?}, max {:?}, {} iterations)\n",
+                    t.day, t.step, t.mean, t.min, t.max, t.iterations
+                );
+            }
+        }
+        ReportFormat::Json => {
+            let rows: Vec<String> = timings
+                .iter()
+                .map(|t| {
+                    format!(
+                        "  {{\"day\": {}, \"step\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"mean_ns\": {}, \"max_ns\": {}}}",
+                        t.day,
+                        t.step,
+                        t.iterations,
+                        t.min.as_nanos(),
+                        t.mean.as_nanos(),
+                        t.max.as_nanos()
+                    )
+                })
+                .collect();
+            res += &format!("[\n{}\n]\n", rows.join(",\n"));
+        }
+        ReportFormat::Csv => {
+            res += "day,step,iterations,min_ns,mean_ns,max_ns\n";
+            for t in timings {
+                res += &format!(
+                    "{},{},{},{},{},{}\n",
+                    t.day,
+                    t.step,
+                    t.iterations,
+                    t.min.as_nanos(),
+                    t.mean.as_nanos(),
+                    t.max.as_nanos()
+                );
+            }
+        }
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings() -> Vec<Timing> {
+        return vec![
+            Timing {
+                day: 8,
+                step: "parse",
+                iterations: 3,
+                min: Duration::from_nanos(1000),
+                mean: Duration::from_nanos(1500),
+                max: Duration::from_nanos(2500),
+            },
+            Timing {
+                day: 8,
+                step: "part1",
+                iterations: 3,
+                min: Duration::from_micros(2),
+                mean: Duration::from_micros(3),
+                max: Duration::from_micros(4),
+            },
+        ];
+    }
+
+    #[test]
+    fn it_should_summarize_durations() {
+        let durations = vec![
+            Duration::from_nanos(30),
+            Duration::from_nanos(10),
+            Duration::from_nanos(20),
+        ];
+        let actual = Timing::new(15, "part2", &durations);
+        assert_eq!(actual.iterations, 3);
+        assert_eq!(actual.min, Duration::from_nanos(10));
+        assert_eq!(actual.mean, Duration::from_nanos(20));
+        assert_eq!(actual.max, Duration::from_nanos(30));
+    }
+
+    #[test]
+    fn it_should_report_as_csv() {
+        assert_eq!(
+            report(&timings(), ReportFormat::Csv),
+            "day,step,iterations,min_ns,mean_ns,max_ns\n8,parse,3,1000,1500,2500\n8,part1,3,2000,3000,4000\n"
+        );
+    }
+
+    #[test]
+    fn it_should_report_as_json() {
+        assert_eq!(
+            report(&timings(), ReportFormat::Json),
+            "[\n  {\"day\": 8, \"step\": \"parse\", \"iterations\": 3, \"min_ns\": 1000, \"mean_ns\": 1500, \"max_ns\": 2500},\n  {\"day\": 8, \"step\": \"part1\", \"iterations\": 3, \"min_ns\": 2000, \"mean_ns\": 3000, \"max_ns\": 4000}\n]\n"
+        );
+    }
+}
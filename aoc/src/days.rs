@@ -0,0 +1,81 @@
+use aoc_core::{Answer, Solution};
+
+use crate::bench::{self, Timing};
+
+// (part, answer) of every solved part
+pub type Answers = Vec<(u8, String)>;
+
+pub struct Day {
+    pub number: u8,
+    pub dir: &'static str,
+    pub default_input: &'static str,
+    pub solve: fn(&str, Option<u8>) -> Result<Answers, String>,
+    pub bench: fn(&str, u32) -> Result<Vec<Timing>, String>,
+}
+
+/**
+ Register a day crate and the type implementing its `Solution`
+*/
+macro_rules! day {
+    ($number:expr, $day:ident, $solution:ident, $input:expr) => {
+        Day {
+            number: $number,
+            dir: concat!(env!("CARGO_MANIFEST_DIR"), "/../", stringify!($day)),
+            default_input: concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../",
+                stringify!($day),
+                "/",
+                $input
+            ),
+            solve: solve::<$day::$solution>,
+            bench: |input, iterations| {
+                bench::measure::<$day::$solution>($number, input, iterations)
+            },
+        }
+    };
+}
+
+/**
+ Parse `input` once then return the (part, answer) of each requested part, `part` is None when
+ both parts should run. A part without an answer is an error
+*/
+fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Answers, String> {
+    let data = S::parse(input).map_err(|err| err.to_string())?;
+    let mut answers: Answers = Vec::new();
+    if part != Some(2) {
+        answers.push((1, S::part1(&data).render()?));
+    }
+    if part != Some(1) {
+        answers.push((2, S::part2(&data).render()?));
+    }
+    return Ok(answers);
+}
+
+pub fn all() -> Vec<Day> {
+    return vec![
+        day!(1, day01, Day01, "input.txt"),
+        day!(2, day02, Day02, "input1.txt"),
+        day!(3, day03, Day03, "input1.txt"),
+        day!(4, day04, Day04, "input1.txt"),
+        day!(5, day05, Day05, "input1.txt"),
+        day!(6, day06, Day06, "input1.txt"),
+        day!(7, day07, Day07, "input1.txt"),
+        day!(8, day08, Day08, "input1.txt"),
+        day!(9, day09, Day09, "input1.txt"),
+        day!(10, day10, Day10, "input1.txt"),
+        day!(11, day11, Day11, "input1.txt"),
+        day!(12, day12, Day12, "input1.txt"),
+        day!(13, day13, Day13, "input1.txt"),
+        day!(14, day14, Day14, "input1.txt"),
+        day!(15, day15, Day15, "input1.txt"),
+        day!(16, day16, Day16, "input1.txt"),
+        day!(17, day17, Day17, "input1.txt"),
+        day!(18, day18, Day18, "input1.txt"),
+        day!(19, day19, Day19, "input1.txt"),
+    ];
+}
+
+pub fn find(number: u8) -> Option<Day> {
+    return all().into_iter().find(|day| day.number == number);
+}
@@ -0,0 +1,573 @@
+use std::io::prelude::*;
+
+use aoc_core::{read_input, Heading, Solution, Vec2};
+
+mod answers;
+mod bench;
+mod days;
+
+use bench::ReportFormat;
+
+const USAGE: &str = "Usage: aoc run <day|all> [--part 1|2] [--input PATH | --data TEXT]
+       aoc bench <day|all> [--iterations N] [--format text|json|csv]
+       aoc check
+       aoc debug [--input PATH]
+       aoc animate <11|17> [--part 1|2] [--input PATH] [--format text|gif]
+       aoc route [--part 1|2] [--input PATH] [--format stats|svg]
+       aoc emulate [--part 1|2] [--input PATH] [--format trace|json|diff]
+    <day>           day number (1-25) or `all`
+    --part 1|2      run only one part (default: both)
+    --input PATH    puzzle input file, `-` reads stdin (default: the day input file)
+    --data TEXT     the puzzle input itself, like the day 15 starting numbers `0,3,6`
+    --iterations N  time parse, part1 and part2 N times each (default: 10)
+    --format        benchmark report format (default: text)
+    check           compare every solution with the answers manifest
+    debug           step through a day 8 console program (default: day08/input1.txt)
+    animate         every generation of the day 11 seating or day 17 cubes (3D for part 1, 4D
+                    for part 2) as text frames or as an animated GIF (default: text)
+    route           the day 12 ship route as statistics or as an SVG path (default: stats)
+    emulate         run the day 14 program with the part 1 or 2 decoder and print every write
+                    (trace), the final memory as JSON, or where both decoders disagree (diff)";
+
+const DEFAULT_ITERATIONS: u32 = 10;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Command {
+    // Run(day (None = all), part (None = both), input)
+    Run(Option<u8>, Option<u8>, Option<Source>),
+    // Bench(day (None = all), iterations, report format)
+    Bench(Option<u8>, u32, ReportFormat),
+    Check,
+    // Debug(day 8 program path)
+    Debug(Option<String>),
+    // Animate(day, part, input path, frames format)
+    Animate(u8, u8, Option<String>, FrameFormat),
+    // Route(part, input path, route format)
+    Route(u8, Option<String>, RouteFormat),
+    // Emulate(part, input path, output format)
+    Emulate(u8, Option<String>, EmulateFormat),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Source {
+    File(String), // path, `-` for stdin
+    Text(String), // the puzzle input itself
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FrameFormat {
+    Text,
+    Gif,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RouteFormat {
+    Stats,
+    Svg,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EmulateFormat {
+    Trace,
+    Json,
+    Diff,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let res = parse_args(&args).and_then(execute);
+    if let Err(err) = res {
+        eprintln!("{}", err);
+        std::process::exit(1);
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut it = args.iter();
+    let command = match it.next().map(|s| s.as_str()) {
+        Some(command @ ("run" | "bench")) => command,
+        Some("check") => {
+            return match it.next() {
+                None => Ok(Command::Check),
+                Some(other) => Err(format!("Unknown argument {:?}\n{}", other, USAGE)),
+            };
+        }
+        Some("debug") => {
+            let (_, input, _) = parse_tool_args(it.as_slice(), false, &[])?;
+            if input.as_deref() == Some("-") {
+                return Err("Debug needs a program file, stdin reads the commands".to_string());
+            }
+            return Ok(Command::Debug(input));
+        }
+        Some("animate") => {
+            let day = match it.next() {
+                Some(day_str) if day_str == "11" || day_str == "17" => parse_day(day_str)?,
+                Some(_) => return Err("Only days 11 and 17 can be animated".to_string()),
+                None => return Err(format!("Missing day\n{}", USAGE)),
+            };
+            let (part, input, format) = parse_tool_args(it.as_slice(), true, &["text", "gif"])?;
+            let format = match format {
+                "text" => FrameFormat::Text,
+                _ => FrameFormat::Gif,
+            };
+            return Ok(Command::Animate(day, part, input, format));
+        }
+        Some("route") => {
+            let (part, input, format) = parse_tool_args(it.as_slice(), true, &["stats", "svg"])?;
+            let format = match format {
+                "stats" => RouteFormat::Stats,
+                _ => RouteFormat::Svg,
+            };
+            return Ok(Command::Route(part, input, format));
+        }
+        Some("emulate") => {
+            let (part, input, format) =
+                parse_tool_args(it.as_slice(), true, &["trace", "json", "diff"])?;
+            let format = match format {
+                "trace" => EmulateFormat::Trace,
+                "json" => EmulateFormat::Json,
+                _ => EmulateFormat::Diff,
+            };
+            return Ok(Command::Emulate(part, input, format));
+        }
+        Some(other) => return Err(format!("Unknown command {:?}\n{}", other, USAGE)),
+        None => return Err(USAGE.to_string()),
+    };
+    let day = match it.next().map(|s| s.as_str()) {
+        Some("all") => None,
+        Some(day_str) => Some(parse_day(day_str)?),
+        None => return Err(format!("Missing day\n{}", USAGE)),
+    };
+    let mut part: Option<u8> = None;
+    let mut input: Option<String> = None;
+    let mut data: Option<String> = None;
+    let mut iterations: u32 = DEFAULT_ITERATIONS;
+    let mut format = ReportFormat::Text;
+    while let Some(arg) = it.next() {
+        match (command, arg.as_str()) {
+            ("run", "--part") => {
+                part = match it.next().map(|s| s.as_str()) {
+                    Some("1") => Some(1),
+                    Some("2") => Some(2),
+                    other => return Err(format!("Part should be 1 or 2, got {:?}", other)),
+                }
+            }
+            ("run", "--input") => match it.next() {
+                Some(path) => input = Some(path.clone()),
+                None => return Err("Missing path after --input".to_string()),
+            },
+            ("run", "--data") => match it.next() {
+                Some(text) => data = Some(text.clone()),
+                None => return Err("Missing text after --data".to_string()),
+            },
+            ("bench", "--iterations") => {
+                iterations = match it.next().map(|s| s.parse::<u32>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("Iterations should be a positive number".to_string()),
+                }
+            }
+            ("bench", "--format") => {
+                format = match it.next().map(|s| s.as_str()) {
+                    Some("text") => ReportFormat::Text,
+                    Some("json") => ReportFormat::Json,
+                    Some("csv") => ReportFormat::Csv,
+                    other => {
+                        return Err(format!(
+                            "Format should be text, json or csv, got {:?}",
+                            other
+                        ))
+                    }
+                }
+            }
+            (_, other) => return Err(format!("Unknown argument {:?}\n{}", other, USAGE)),
+        }
+    }
+    if command == "bench" {
+        return Ok(Command::Bench(day, iterations, format));
+    }
+    let source = match (input, data) {
+        (Some(_), Some(_)) => return Err("--input and --data cannot be used together".to_string()),
+        (Some(path), None) => Some(Source::File(path)),
+        (None, data) => data.map(Source::Text),
+    };
+    if day.is_none() && source.is_some() {
+        return Err("--input and --data cannot be used when running all days".to_string());
+    }
+    return Ok(Command::Run(day, part, source));
+}
+
+/**
+ `[--part 1|2] [--input PATH] [--format F]` arguments of a single day tool (part 1 by default),
+ the first of the `formats` being the default one. `--part` is only accepted with `parts`, and
+ `--format` when there are `formats`.
+*/
+fn parse_tool_args<'a>(
+    args: &[String],
+    parts: bool,
+    formats: &[&'a str],
+) -> Result<(u8, Option<String>, &'a str), String> {
+    let mut it = args.iter();
+    let mut part: u8 = 1;
+    let mut input: Option<String> = None;
+    let mut format = formats.first().copied().unwrap_or_default();
+    while let Some(arg) = it.next() {
+        match arg.as_str() {
+            "--part" if parts => {
+                part = match it.next().map(|s| s.as_str()) {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    other => return Err(format!("Part should be 1 or 2, got {:?}", other)),
+                }
+            }
+            "--input" => match it.next() {
+                Some(path) => input = Some(path.clone()),
+                None => return Err("Missing path after --input".to_string()),
+            },
+            "--format" if !formats.is_empty() => {
+                let value = it.next().map(|s| s.as_str());
+                format = match formats.iter().find(|f| Some(**f) == value) {
+                    Some(f) => f,
+                    None => {
+                        return Err(format!(
+                            "Format should be {}, got {:?}",
+                            formats.join(" or "),
+                            value
+                        ))
+                    }
+                }
+            }
+            other => return Err(format!("Unknown argument {:?}\n{}", other, USAGE)),
+        }
+    }
+    return Ok((part, input, format));
+}
+
+fn parse_day(day_str: &str) -> Result<u8, String> {
+    return match day_str.parse::<u8>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!(
+            "Day should be a number between 1 and 25, got {:?}",
+            day_str
+        )),
+    };
+}
+
+fn execute(command: Command) -> Result<(), String> {
+    let (day, part, source) = match command {
+        Command::Run(day, part, source) => (day, part, source),
+        Command::Bench(day, iterations, format) => return bench(day, iterations, format),
+        Command::Check => return check(),
+        Command::Debug(input) => return debug(input),
+        Command::Animate(day, part, input, format) => return animate(day, part, input, format),
+        Command::Route(part, input, format) => return route(part, input, format),
+        Command::Emulate(part, input, format) => return emulate(part, input, format),
+    };
+    for day in select_days(day)? {
+        let input = match &source {
+            Some(Source::Text(text)) => text.clone(),
+            Some(Source::File(path)) => load_input(path)?,
+            None => load_input(day.default_input)?,
+        };
+        println!("Day {:02}", day.number);
+        for (part, answer) in (day.solve)(&input, part)? {
+            println!("Part{}: {}", part, answer);
+        }
+    }
+    return Ok(());
+}
+
+fn select_days(day: Option<u8>) -> Result<Vec<days::Day>, String> {
+    return match day {
+        Some(number) => {
+            Ok(vec![days::find(number).ok_or_else(|| {
+                format!("Day {} is not solved yet", number)
+            })?])
+        }
+        None => Ok(days::all()),
+    };
+}
+
+fn bench(day: Option<u8>, iterations: u32, format: ReportFormat) -> Result<(), String> {
+    let mut timings: Vec<bench::Timing> = Vec::new();
+    for day in select_days(day)? {
+        timings.extend((day.bench)(&load_input(day.default_input)?, iterations)?);
+    }
+    print!("{}", bench::report(&timings, format));
+    return Ok(());
+}
+
+fn debug(input: Option<String>) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| days::find(8).unwrap().default_input.to_string());
+    let program = day08::Day08::parse(&load_input(&path)?).map_err(|err| err.to_string())?;
+    let stdin = std::io::stdin();
+    return day08::debugger::repl(&program, stdin.lock(), std::io::stdout())
+        .map_err(|err| format!("Debugger I/O error: {}", err));
+}
+
+fn animate(day: u8, part: u8, input: Option<String>, format: FrameFormat) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| days::find(day).unwrap().default_input.to_string());
+    let input = load_input(&path)?;
+    let output: Vec<u8> = if day == 11 {
+        let seats = day11::Day11::parse(&input).map_err(|err| err.to_string())?;
+        let rules = if part == 1 {
+            day11::PART1_RULES
+        } else {
+            day11::PART2_RULES
+        };
+        match format {
+            FrameFormat::Text => day11::frames::text_frames(&seats, rules).into_bytes(),
+            FrameFormat::Gif => day11::frames::animation(&seats, rules)
+                .to_gif()
+                .map_err(|err| err.to_string())?,
+        }
+    } else {
+        let cubes = day17::Day17::parse(&input).map_err(|err| err.to_string())?;
+        let dimensions = part as usize + 2;
+        match format {
+            FrameFormat::Text => day17::frames::text_frames(&cubes, dimensions, 6).into_bytes(),
+            FrameFormat::Gif => day17::frames::animation(&cubes, dimensions, 6)
+                .to_gif()
+                .map_err(|err| err.to_string())?,
+        }
+    };
+    return std::io::stdout()
+        .write_all(&output)
+        .map_err(|err| format!("Cannot write the frames: {}", err));
+}
+
+fn route(part: u8, input: Option<String>, format: RouteFormat) -> Result<(), String> {
+    let path = input.unwrap_or_else(|| days::find(12).unwrap().default_input.to_string());
+    let moves = day12::Day12::parse(&load_input(&path)?).map_err(|err| err.to_string())?;
+    let route = if part == 1 {
+        day12::route::trace(day12::Ship::new(Heading::East), &moves)
+    } else {
+        day12::route::trace(day12::WaypointShip::new(Vec2::new(10, 1)), &moves)
+    };
+    match format {
+        RouteFormat::Stats => {
+            let end = route.positions.last().copied().unwrap_or(Vec2::ZERO);
+            println!("Moves: {}", moves.len());
+            println!("Final position: ({}, {})", end.x, end.y);
+            print!("{}", route.stats());
+        }
+        RouteFormat::Svg => print!("{}", route.to_svg()),
+    }
+    return Ok(());
+}
+
+fn emulate(part: u8, input: Option<String>, format: EmulateFormat) -> Result<(), String> {
+    use day14::emulator::{diff, Decoder, Emulator};
+
+    let path = input.unwrap_or_else(|| days::find(14).unwrap().default_input.to_string());
+    let program = day14::Day14::parse(&load_input(&path)?).map_err(|err| err.to_string())?;
+    let run = |decoder: Decoder| {
+        let mut emulator = Emulator::with_trace(decoder);
+        emulator.run(&program);
+        return emulator;
+    };
+    let emulator = run(if part == 1 { Decoder::V1 } else { Decoder::V2 });
+    match format {
+        EmulateFormat::Trace => {
+            for write in emulator.trace() {
+                println!("{}", write);
+            }
+            println!("Sum: {}", emulator.memory().sum());
+        }
+        EmulateFormat::Json => print!("{}", emulator.to_json()),
+        EmulateFormat::Diff => {
+            let (v1, v2) = (run(Decoder::V1), run(Decoder::V2));
+            println!("Addresses: v1 / v2 values");
+            for difference in diff(v1.memory(), v2.memory()) {
+                println!("{}", difference);
+            }
+        }
+    }
+    return Ok(());
+}
+
+fn check() -> Result<(), String> {
+    let manifest = read_input(answers::MANIFEST)
+        .map_err(|err| format!("Cannot read {}: {}", answers::MANIFEST, err))?;
+    let known_answers = answers::parse_manifest(&manifest)?;
+    let mut failed: usize = 0;
+    for known in &known_answers {
+        let status = match answers::check(known) {
+            Ok(()) => "ok".to_string(),
+            Err(err) => {
+                failed += 1;
+                format!("FAILED: {}", err)
+            }
+        };
+        println!(
+            "Day {:02} part {} ({}): {}",
+            known.day, known.part, known.input, status
+        );
+    }
+    if failed > 0 {
+        return Err(format!(
+            "{} of {} answers failed",
+            failed,
+            known_answers.len()
+        ));
+    }
+    return Ok(());
+}
+
+fn load_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut contents = String::new();
+        std::io::stdin()
+            .read_to_string(&mut contents)
+            .map_err(|err| format!("Cannot read stdin: {}", err))?;
+        return Ok(contents);
+    }
+    return read_input(path).map_err(|err| format!("Cannot read {}: {}", path, err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        return line.split(' ').map(|s| s.to_string()).collect();
+    }
+
+    #[test]
+    fn it_should_parse_run_command() {
+        assert_eq!(
+            parse_args(&args("run 4")),
+            Ok(Command::Run(Some(4), None, None))
+        );
+        assert_eq!(
+            parse_args(&args("run 15 --part 2 --input -")),
+            Ok(Command::Run(
+                Some(15),
+                Some(2),
+                Some(Source::File("-".to_string()))
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("run 15 --data 0,3,6")),
+            Ok(Command::Run(
+                Some(15),
+                None,
+                Some(Source::Text("0,3,6".to_string()))
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("run all --part 1")),
+            Ok(Command::Run(None, Some(1), None))
+        );
+        assert_eq!(parse_args(&args("check")), Ok(Command::Check));
+        assert_eq!(
+            parse_args(&args("debug --input prog.txt")),
+            Ok(Command::Debug(Some("prog.txt".to_string())))
+        );
+        assert_eq!(parse_args(&args("debug")), Ok(Command::Debug(None)));
+    }
+
+    #[test]
+    fn it_should_parse_bench_command() {
+        assert_eq!(
+            parse_args(&args("bench 7")),
+            Ok(Command::Bench(
+                Some(7),
+                DEFAULT_ITERATIONS,
+                ReportFormat::Text
+            ))
+        );
+        assert_eq!(
+            parse_args(&args("bench all --iterations 3 --format csv")),
+            Ok(Command::Bench(None, 3, ReportFormat::Csv))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_animate_command() {
+        assert_eq!(
+            parse_args(&args("animate 11")),
+            Ok(Command::Animate(11, 1, None, FrameFormat::Text))
+        );
+        assert_eq!(
+            parse_args(&args("animate 17 --part 2 --format gif --input cubes.txt")),
+            Ok(Command::Animate(
+                17,
+                2,
+                Some("cubes.txt".to_string()),
+                FrameFormat::Gif
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_route_command() {
+        assert_eq!(
+            parse_args(&args("route")),
+            Ok(Command::Route(1, None, RouteFormat::Stats))
+        );
+        assert_eq!(
+            parse_args(&args("route --format svg --part 2 --input moves.txt")),
+            Ok(Command::Route(
+                2,
+                Some("moves.txt".to_string()),
+                RouteFormat::Svg
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_parse_emulate_command() {
+        assert_eq!(
+            parse_args(&args("emulate --part 2")),
+            Ok(Command::Emulate(2, None, EmulateFormat::Trace))
+        );
+        assert_eq!(
+            parse_args(&args("emulate --format diff --input prog.txt")),
+            Ok(Command::Emulate(
+                1,
+                Some("prog.txt".to_string()),
+                EmulateFormat::Diff
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_arguments() {
+        assert!(parse_args(&args("walk 4")).is_err());
+        assert!(parse_args(&args("run 26")).is_err());
+        assert!(parse_args(&args("run 4 --part 3")).is_err());
+        assert!(parse_args(&args("run 4 --input")).is_err());
+        assert!(parse_args(&args("run all --input input1.txt")).is_err());
+        assert!(parse_args(&args("run all --data 0,3,6")).is_err());
+        assert!(parse_args(&args("run 15 --data 0,3,6 --input -")).is_err());
+        assert!(parse_args(&args("animate 11 --data L.L")).is_err());
+        assert!(parse_args(&args("check 4")).is_err());
+        assert!(parse_args(&args("debug --input -")).is_err());
+        assert!(parse_args(&args("debug --part 2")).is_err());
+        assert!(parse_args(&args("debug --format text")).is_err());
+        assert!(parse_args(&args("debug --input")).is_err());
+        assert!(parse_args(&args("bench 4 --part 1")).is_err());
+        assert!(parse_args(&args("bench 4 --iterations 0")).is_err());
+        assert!(parse_args(&args("bench 4 --format xml")).is_err());
+        assert!(parse_args(&args("run 4 --format json")).is_err());
+        assert!(parse_args(&args("animate 4")).is_err());
+        assert!(parse_args(&args("animate 11 --format png")).is_err());
+        assert!(parse_args(&args("animate all")).is_err());
+        assert!(parse_args(&args("animate 11 --part")).is_err());
+        assert!(parse_args(&args("animate 17 --iterations 3")).is_err());
+        assert!(parse_args(&args("route 12")).is_err());
+        assert!(parse_args(&args("route --format gif")).is_err());
+        assert!(parse_args(&args("emulate --format svg")).is_err());
+    }
+
+    #[test]
+    fn it_should_register_every_day_input() {
+        for day in days::all() {
+            assert!(
+                std::path::Path::new(day.default_input).exists(),
+                "Missing input for day {}",
+                day.number
+            );
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_core::{parse_numbers, ParseError, Solution};
+
+const DAY: u8 = 1;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<u32>, ParseError> {
+    return parse_numbers(DAY, &input);
+}
+
+#[allow(clippy::collapsible_if)]
+fn part1(arr_data: &Vec<u32>) -> u32 {
+    let mut number_that_sum_2020_1 = 0;
+    let mut number_that_sum_2020_2 = 0;
+
+    'fori: for (posi, i) in arr_data.iter().enumerate() {
+        for (posj, j) in arr_data.iter().enumerate() {
+            if posi != posj {
+                if i + j == 2020 {
+                    number_that_sum_2020_1 = *i;
+                    number_that_sum_2020_2 = *j;
+                    break 'fori;
+                }
+            }
+        }
+    }
+
+    return number_that_sum_2020_1 * number_that_sum_2020_2;
+}
+
+#[allow(clippy::collapsible_if)]
+fn part2(arr_data: &Vec<u32>) -> u32 {
+    let mut number_that_sum_2020_1 = 0;
+    let mut number_that_sum_2020_2 = 0;
+    let mut number_that_sum_2020_3 = 0;
+
+    'fori: for (posi, i) in arr_data.iter().enumerate() {
+        for (posj, j) in arr_data.iter().enumerate() {
+            if posi != posj {
+                for (posk, k) in arr_data.iter().enumerate() {
+                    if posi != posk && posj != posk {
+                        if i + j + k == 2020 {
+                            number_that_sum_2020_1 = *i;
+                            number_that_sum_2020_2 = *j;
+                            number_that_sum_2020_3 = *k;
+                            break 'fori;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    return number_that_sum_2020_1 * number_that_sum_2020_2 * number_that_sum_2020_3;
+}
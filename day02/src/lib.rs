@@ -0,0 +1,109 @@
+use aoc_core::{lines, ParseError, Solution};
+
+const DAY: u8 = 2;
+
+#[derive(Debug)]
+pub struct PasswordLine {
+    min: usize,
+    max: usize,
+    character: String,
+    password: String,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<PasswordLine>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<PasswordLine>, ParseError> {
+    return lines(&input)
+        // 17-20 x: zsxjrxkgxxxxxxxmxgxf
+        .map(|line| {
+            let split: Vec<&str> = line
+                .split(['-', ' ', ':'])
+                .filter(|s| !s.is_empty())
+                .collect();
+            // [17, 20, x, zsxjrxkgxxxxxxxmxgxf]
+            if split.len() != 4 {
+                return Err(ParseError::at(
+                    DAY,
+                    &input,
+                    line,
+                    "Should be formatted as \"<min>-<max> <char>: <password>\"",
+                ));
+            }
+            let parse_usize = |s: &str| {
+                s.parse::<usize>()
+                    .map_err(|_| ParseError::at(DAY, &input, s, "Cannot parse as usize"))
+            };
+            return Ok(PasswordLine {
+                min: parse_usize(split[0])?,
+                max: parse_usize(split[1])?,
+                character: split[2].to_string(),
+                password: split[3].to_string(),
+            });
+        })
+        .collect();
+}
+
+fn part1(arr_data: &Vec<PasswordLine>) -> usize {
+    let valid_ones: Vec<&PasswordLine> = arr_data
+        .iter()
+        .filter(|line| {
+            let matches: Vec<&str> = line.password.matches(&line.character).collect();
+            let match_count = matches.len();
+            let is_valid: bool = line.min <= match_count && match_count <= line.max;
+            // if !is_valid {
+            //     println!("Invalid: {:?} (matches: {:?})", line, match_count);
+            // }
+            return is_valid;
+        })
+        .collect();
+
+    return valid_ones.len();
+}
+
+fn part2(arr_data: &Vec<PasswordLine>) -> usize {
+    let valid_ones: Vec<&PasswordLine> = arr_data
+        .iter()
+        .filter(|line| {
+            let first = line
+                .password
+                .chars()
+                .nth(line.min - 1)
+                .unwrap_or_else(|| panic!("Cannot get char {:?}[{:?}]", line, line.min));
+            let second = line
+                .password
+                .chars()
+                .nth(line.max - 1)
+                .unwrap_or_else(|| panic!("Cannot get char {:?}[{:?}]", line, line.max));
+            // ^ = XOR
+            let is_valid: bool =
+                (line.character.contains(first)) ^ (line.character.contains(second));
+            // if !is_valid {
+            //     println!(
+            //         "Invalid: {:?} (extracted: {:?}:{:?} {:?}:{:?})",
+            //         line, line.min, first, line.max, second
+            //     );
+            // }
+            return is_valid;
+        })
+        .collect();
+
+    return valid_ones.len();
+}
@@ -0,0 +1,75 @@
+use aoc_core::{Axis, Grid, ParseError, Solution};
+
+const DAY: u8 = 3;
+
+#[derive(Debug)]
+pub enum MapPosition {
+    OpenSquare,
+    Tree,
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<MapPosition>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Grid<MapPosition>, ParseError> {
+    // ..#...##...###.........#..#..#.
+    return Grid::parse(
+        DAY,
+        &input,
+        |pos| match pos {
+            '.' => Some(MapPosition::OpenSquare),
+            '#' => Some(MapPosition::Tree),
+            _ => None,
+        },
+        "Invalid map position",
+    );
+}
+
+fn part1(arr_data: &Grid<MapPosition>) -> u64 {
+    return trees_encounter(arr_data, 3, 1);
+}
+
+fn part2(arr_data: &Grid<MapPosition>) -> u64 {
+    let encounters = [
+        trees_encounter(arr_data, 1, 1),
+        trees_encounter(arr_data, 3, 1),
+        trees_encounter(arr_data, 5, 1),
+        trees_encounter(arr_data, 7, 1),
+        trees_encounter(arr_data, 1, 2),
+    ];
+    return encounters.iter().product::<u64>();
+}
+
+/**
+ The map repeats itself to the right as many times as needed
+*/
+fn trees_encounter(arr_data: &Grid<MapPosition>, right_move: usize, bottom_move: usize) -> u64 {
+    let encounter = (0..arr_data.height())
+        .step_by(bottom_move)
+        .enumerate()
+        .filter(|(step, y)| {
+            return matches!(
+                arr_data.get_wrapping(step * right_move, *y, Axis::X),
+                Some(MapPosition::Tree)
+            );
+        })
+        .count();
+    return encounter as u64;
+}
@@ -1,11 +1,12 @@
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::prelude::*;
 
+use aoc_core::{groups, split_once, ParseError, Solution};
 use regex::Regex;
 
+const DAY: u8 = 4;
+
 #[derive(Clone, Debug)]
-struct Passport {
+pub struct Passport {
     byr: Option<String>, // Birth Year
     iyr: Option<String>, // Issue Year
     eyr: Option<String>, // Expiration Year
@@ -14,32 +15,34 @@ struct Passport {
     hgt: Option<String>, // Height
     hcl: Option<String>, // Hair Color
     ecl: Option<String>, // Eye Color
+    #[allow(dead_code)]
     file_fragment: String,
 }
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let arr_data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day04;
 
-    part1(&arr_data1);
-    part2(&arr_data1);
-    Ok(())
-}
+impl Solution for Day04 {
+    type Input = VecDeque<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<VecDeque<Passport>, String> {
-    let parsed = input
-        .split("\n\n")
+fn parse_data(input: String) -> Result<VecDeque<Passport>, ParseError> {
+    return groups(&input)
+        .into_iter()
         .map(|file_fragment| {
-            if file_fragment.is_empty() {
-                return Err("Empty passport");
-            }
             let mut new_passport = Passport {
                 byr: None,
                 iyr: None,
@@ -51,47 +54,31 @@ fn parse_data(input: String) -> Result<VecDeque<Passport>, String> {
                 ecl: None,
                 file_fragment: file_fragment.to_string(),
             };
-            file_fragment
-                // ecl:grn\ncid:315 iyr:2012 hgt:192cm eyr:2023 pid:873355140 byr:1925 hcl:#cb2c03
-                .split(|c| c == '\n' || c == ' ')
-                // [ ecl:grn, cid:315, iyr:2012, hgt:192cm, eyr:2023, pid:873355140, byr:1925, hcl:#cb2c03 ]
-                .for_each(|kv| {
-                    let split: Vec<&str> = kv.split(":").collect(); // ecl:grn => [ecl, grn]
-                    match split.get(0) {
-                        Some(&"byr") => new_passport.byr = try_parse_as_string(&split, 1),
-                        Some(&"iyr") => new_passport.iyr = try_parse_as_string(&split, 1),
-                        Some(&"eyr") => new_passport.eyr = try_parse_as_string(&split, 1),
-                        Some(&"cid") => new_passport.cid = try_parse_as_string(&split, 1),
-                        Some(&"pid") => new_passport.pid = try_parse_as_string(&split, 1),
-                        Some(&"hgt") => new_passport.hgt = try_parse_as_string(&split, 1),
-                        Some(&"hcl") => new_passport.hcl = try_parse_as_string(&split, 1),
-                        Some(&"ecl") => new_passport.ecl = try_parse_as_string(&split, 1),
-                        Some(_) => panic!("Should not be detect as a key-value: {:?}", kv),
-                        _ => panic!(
-                            "Should not get an empty key-value: {:?} {:?}",
-                            kv, file_fragment
-                        ),
-                    }
-                });
+            // ecl:grn\ncid:315 iyr:2012 hgt:192cm eyr:2023 pid:873355140 byr:1925 hcl:#cb2c03
+            for kv in file_fragment.split_whitespace() {
+                // ecl:grn => (ecl, grn)
+                let (key, value) = split_once(kv, ":").ok_or_else(|| {
+                    ParseError::at(DAY, &input, kv, "Should be formatted as \"<key>:<value>\"")
+                })?;
+                let field = match key {
+                    "byr" => &mut new_passport.byr,
+                    "iyr" => &mut new_passport.iyr,
+                    "eyr" => &mut new_passport.eyr,
+                    "cid" => &mut new_passport.cid,
+                    "pid" => &mut new_passport.pid,
+                    "hgt" => &mut new_passport.hgt,
+                    "hcl" => &mut new_passport.hcl,
+                    "ecl" => &mut new_passport.ecl,
+                    _ => return Err(ParseError::at(DAY, &input, key, "Unknown passport field")),
+                };
+                *field = Some(value.to_string());
+            }
             return Ok(new_passport);
         })
-        .filter(Result::is_ok)
-        .map(|passport| passport.expect("Should be a passport"))
         .collect();
-    return Ok(parsed);
-}
-
-fn try_parse_as_string(kv: &Vec<&str>, index: usize) -> Option<String> {
-    return match kv.get(index) {
-        Some(str_value) => Some(str_value.to_string()),
-        _ => {
-            println!("No value for this kv {:?} {:?}", kv, 1);
-            return None;
-        }
-    };
 }
 
-fn part1(arr_data: &VecDeque<Passport>) {
+fn part1(arr_data: &VecDeque<Passport>) -> usize {
     let valid_count: usize = arr_data
         .iter()
         .filter(|passport| {
@@ -108,40 +95,40 @@ fn part1(arr_data: &VecDeque<Passport>) {
             return is_valid;
         })
         .count();
-    println!("Part1: {:?}/{:?}", valid_count, arr_data.len());
+    return valid_count;
 }
 
-fn part2(arr_data: &VecDeque<Passport>) {
+fn part2(arr_data: &VecDeque<Passport>) -> usize {
     let valid_count: usize = arr_data
         .iter()
         .filter(|passport| {
             let is_valid = passport.byr.is_some()
-                && valid_number_range(&passport.byr.as_ref().unwrap(), 1920, 2002)
+                && valid_number_range(passport.byr.as_ref().unwrap(), 1920, 2002)
                 // issue year
                 && passport.iyr.is_some()
-                && valid_number_range(&passport.iyr.as_ref().unwrap(), 2010, 2020)
+                && valid_number_range(passport.iyr.as_ref().unwrap(), 2010, 2020)
                 // expiration year
                 && passport.eyr.is_some()
-                && valid_number_range(&passport.eyr.as_ref().unwrap(), 2020, 2030)
+                && valid_number_range(passport.eyr.as_ref().unwrap(), 2020, 2030)
                 // passport ID
                 && passport.pid.is_some()
-                && valid_passport_id(&passport.pid.as_ref().unwrap())
+                && valid_passport_id(passport.pid.as_ref().unwrap())
                 // height
                 && passport.hgt.is_some()
-                && valid_height(&passport.hgt.as_ref().unwrap())
+                && valid_height(passport.hgt.as_ref().unwrap())
                 // hair color
                 && passport.hcl.is_some()
-                && valid_hair_color(&passport.hcl.as_ref().unwrap())
+                && valid_hair_color(passport.hcl.as_ref().unwrap())
                 // eye color
                 && passport.ecl.is_some()
-                && valid_eye_color(&passport.ecl.as_ref().unwrap());
+                && valid_eye_color(passport.ecl.as_ref().unwrap());
             // if !is_valid {
             //     println!("invalid passport: {:#?}", passport);
             // }
             return is_valid;
         })
         .count();
-    println!("Part2: {:?}/{:?}", valid_count, arr_data.len());
+    return valid_count;
 }
 
 fn valid_number_range(value: &String, min: u64, max: u64) -> bool {
@@ -164,6 +151,7 @@ fn valid_passport_id(value: &String) -> bool {
     return res;
 }
 
+#[allow(clippy::manual_range_contains)]
 fn valid_height(value: &String) -> bool {
     let res = if value.ends_with("cm") {
         let value_without_suffix = value.trim_end_matches("cm");
@@ -211,6 +199,6 @@ mod tests {
 
     #[test]
     fn it_should_valid_hair_color1() {
-        assert_eq!(valid_hair_color(&"#c0946f".to_string()), true);
+        assert!(valid_hair_color(&"#c0946f".to_string()));
     }
 }
@@ -1,39 +1,62 @@
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::prelude::*;
+
+use aoc_core::{lines, ParseError, Solution};
+
+const DAY: u8 = 5;
 
 #[derive(Clone, Debug, PartialEq)]
-struct BoardingPass {
+pub struct BoardingPass {
     row: usize,
     col: usize,
     seat_id: usize,
     file_fragment: String,
 }
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let arr_data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day05;
 
-    part1(&arr_data1);
-    part2(&arr_data1);
-    Ok(())
-}
+impl Solution for Day05 {
+    type Input = VecDeque<BoardingPass>;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<VecDeque<BoardingPass>, String> {
-    let parsed = input
-        .split("\n")
+fn parse_data(input: String) -> Result<VecDeque<BoardingPass>, ParseError> {
+    let passes: VecDeque<BoardingPass> = lines(&input)
         .map(|file_fragment| {
-            if file_fragment.is_empty() {
-                return Err("Empty boarding pass");
+            // FBFBBFFRLR: 7 row chars then 3 column chars
+            if file_fragment.len() != 10 || !file_fragment.is_char_boundary(7) {
+                return Err(ParseError::at(
+                    DAY,
+                    &input,
+                    file_fragment,
+                    "Should be 10 characters long",
+                ));
             }
             let (row_binary_str, col_binary_str) = file_fragment.split_at(7);
+            for (i, c) in row_binary_str.char_indices() {
+                if c != 'F' && c != 'B' {
+                    let fragment = &row_binary_str[i..i + c.len_utf8()];
+                    return Err(ParseError::at(DAY, &input, fragment, "Should be F or B"));
+                }
+            }
+            for (i, c) in col_binary_str.char_indices() {
+                if c != 'L' && c != 'R' {
+                    let fragment = &col_binary_str[i..i + c.len_utf8()];
+                    return Err(ParseError::at(DAY, &input, fragment, "Should be L or R"));
+                }
+            }
             let row_binary = row_binary_str.to_string();
             let col_binary = col_binary_str.to_string();
 
@@ -46,17 +69,23 @@ fn parse_data(input: String) -> Result<VecDeque<BoardingPass>, String> {
                 file_fragment: file_fragment.to_string(),
             });
         })
-        .filter(Result::is_ok)
-        .map(|pass| pass.expect("Should be a pass"))
-        .collect();
-    return Ok(parsed);
+        .collect::<Result<_, ParseError>>()?;
+    if passes.is_empty() {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            &input[input.len()..],
+            "Should contain at least one boarding pass",
+        ));
+    }
+    return Ok(passes);
 }
 
 fn binary_to_number(binary: &String, min: usize, max: usize, index: usize) -> usize {
     if min == max {
         return min;
     }
-    let half = (max - min + 1) / 2;
+    let half = (max - min).div_ceil(2);
     return match binary.chars().nth(index) {
         Some('L') | Some('F') => binary_to_number(binary, min, max - half, index + 1),
         Some('R') | Some('B') => binary_to_number(binary, min + half, max, index + 1),
@@ -64,12 +93,12 @@ fn binary_to_number(binary: &String, min: usize, max: usize, index: usize) -> us
     };
 }
 
-fn part1(arr_data: &VecDeque<BoardingPass>) {
+fn part1(arr_data: &VecDeque<BoardingPass>) -> usize {
     let highest_seat_id = arr_data.iter().map(|pass| pass.seat_id).max();
-    println!("Part1: {:?}", highest_seat_id);
+    return highest_seat_id.expect("Should contain at least one boarding pass");
 }
 
-fn part2(arr_data: &VecDeque<BoardingPass>) {
+fn part2(arr_data: &VecDeque<BoardingPass>) -> Result<usize, &'static str> {
     let mut all_seat_id: Vec<usize> = arr_data.iter().map(|pass| pass.seat_id).collect();
     all_seat_id.sort();
 
@@ -77,18 +106,17 @@ fn part2(arr_data: &VecDeque<BoardingPass>) {
     for row in 0..128 {
         for col in 0..8 {
             let potential_seat_id: usize = row * 8 + col;
-            if potential_seat_id > 0 && !all_seat_id.contains(&potential_seat_id) {
-                if all_seat_id.contains(&(potential_seat_id - 1))
-                    && all_seat_id.contains(&(potential_seat_id + 1))
-                {
-                    println!("Match: {:?},{:?} => {:?}", row, col, potential_seat_id);
-                    my_seat_id.push(potential_seat_id);
-                }
+            if potential_seat_id > 0
+                && !all_seat_id.contains(&potential_seat_id)
+                && all_seat_id.contains(&(potential_seat_id - 1))
+                && all_seat_id.contains(&(potential_seat_id + 1))
+            {
+                my_seat_id.push(potential_seat_id);
             }
         }
     }
 
-    println!("Part2: {:?}", my_seat_id);
+    return my_seat_id.first().copied().ok_or("Should find a free seat");
 }
 
 #[cfg(test)]
@@ -146,4 +174,17 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn it_should_reject_empty_input() {
+        let error = parse_data("\n".to_string()).unwrap_err();
+        assert_eq!(error.message, "Should contain at least one boarding pass");
+    }
+
+    #[test]
+    fn it_should_not_find_a_seat_without_neighbours() {
+        let passes = parse_data("BFFFBBFRRR".to_string()).unwrap();
+        assert_eq!(Day05::part1(&passes), 567);
+        assert_eq!(Day05::part2(&passes), Err("Should find a free seat"));
+    }
 }
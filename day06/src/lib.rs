@@ -1,6 +1,8 @@
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::prelude::*;
+
+use aoc_core::{groups, ParseError, Solution};
+
+const DAY: u8 = 6;
 
 #[derive(Clone, Debug, PartialEq)]
 struct CustomsDeclarationForm {
@@ -9,58 +11,64 @@ struct CustomsDeclarationForm {
 }
 
 #[derive(Clone, Debug, PartialEq)]
-struct GroupCustomsDeclarationForm {
+pub struct GroupCustomsDeclarationForm {
     answers: Vec<CustomsDeclarationForm>,
     file_fragment: String,
 }
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let arr_data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day06;
 
-    part1(&arr_data1);
-    part2(&arr_data1);
-    Ok(())
-}
+impl Solution for Day06 {
+    type Input = Vec<GroupCustomsDeclarationForm>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<Vec<GroupCustomsDeclarationForm>, String> {
-    let parsed = input
-        .split("\n\n")
+fn parse_data(input: String) -> Result<Vec<GroupCustomsDeclarationForm>, ParseError> {
+    return groups(&input)
+        .into_iter()
         .map(|file_fragment| {
-            if file_fragment.is_empty() {
-                return Err("Empty group");
-            }
             let mut new_group_form = GroupCustomsDeclarationForm {
                 answers: Vec::new(),
                 file_fragment: file_fragment.to_string(),
             };
-            file_fragment
-                // qzbw\nqez
-                .split(|c| c == '\n' || c == ' ')
-                // [ qzbw, qez ]
-                .for_each(|person_answers| {
-                    let new_form = CustomsDeclarationForm {
-                        answers: person_answers.chars().collect(),
-                        file_fragment: person_answers.to_string(),
-                    };
-                    new_group_form.answers.push(new_form);
+            // qzbw\nqez => [ qzbw, qez ]
+            for person_answers in file_fragment.split_whitespace() {
+                if let Some((i, c)) = person_answers
+                    .char_indices()
+                    .find(|(_, c)| !c.is_ascii_lowercase())
+                {
+                    let fragment = &person_answers[i..i + c.len_utf8()];
+                    return Err(ParseError::at(
+                        DAY,
+                        &input,
+                        fragment,
+                        "Should be a question from a to z",
+                    ));
+                }
+                new_group_form.answers.push(CustomsDeclarationForm {
+                    answers: person_answers.chars().collect(),
+                    file_fragment: person_answers.to_string(),
                 });
+            }
             return Ok(new_group_form);
         })
-        .filter(Result::is_ok)
-        .map(|group_form| group_form.expect("Should be a group form"))
         .collect();
-    return Ok(parsed);
 }
 
-fn part1(arr_data: &Vec<GroupCustomsDeclarationForm>) {
+fn part1(arr_data: &Vec<GroupCustomsDeclarationForm>) -> usize {
     let result: usize = arr_data
         .iter()
         .map(|group_form| {
@@ -76,36 +84,35 @@ fn part1(arr_data: &Vec<GroupCustomsDeclarationForm>) {
         })
         .map(|group_answer_set| group_answer_set.len())
         .sum();
-    println!("Part1: {:?}", result);
+    return result;
 }
 
-fn part2(arr_data: &Vec<GroupCustomsDeclarationForm>) {
+fn part2(arr_data: &Vec<GroupCustomsDeclarationForm>) -> usize {
     let result: usize = arr_data
         .iter()
         .map(|group_form| {
             // grab first form answers as base result
             let mut intersec_res: HashSet<char> = group_form
                 .answers
-                .get(0)
+                .first()
                 .unwrap()
                 .answers
                 .iter()
-                .map(|c| *c)
+                .copied()
                 .collect();
             // make the intersection (= keep only common value) between intersec_res and next forms one by one
             group_form.answers.iter().skip(1).for_each(|form| {
-                let current_form_answer_set: HashSet<char> =
-                    form.answers.iter().map(|c| *c).collect();
+                let current_form_answer_set: HashSet<char> = form.answers.iter().copied().collect();
                 intersec_res = intersec_res
                     .intersection(&current_form_answer_set)
-                    .map(|c| *c)
+                    .copied()
                     .collect();
             });
             return intersec_res;
         })
         .map(|group_answer_set| group_answer_set.len())
         .sum();
-    println!("Part2: {:?}", result);
+    return result;
 }
 
 #[cfg(test)]
@@ -120,8 +127,17 @@ mod tests {
         let actual = first_parse_result_option.unwrap();
         assert_eq!(
             actual,
-            CustomsDeclarationForm {
-                answers: ['q', 'z', 'b', 'w', 'e', 'z'].iter().cloned().collect(), //HashSet::new(),
+            GroupCustomsDeclarationForm {
+                answers: vec![
+                    CustomsDeclarationForm {
+                        answers: vec!['q', 'z', 'b', 'w'],
+                        file_fragment: "qzbw".to_string(),
+                    },
+                    CustomsDeclarationForm {
+                        answers: vec!['q', 'e', 'z'],
+                        file_fragment: "qez".to_string(),
+                    },
+                ],
                 file_fragment: "qzbw\nqez".to_string(),
             }
         );
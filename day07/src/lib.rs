@@ -1,11 +1,13 @@
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::prelude::*;
 use std::ops::Range;
 
+use aoc_core::{lines, split_once, ParseError, Solution};
+
+const DAY: u8 = 7;
+
 #[derive(Clone, Debug, PartialEq)]
-struct BagDefinition {
+pub struct BagDefinition {
     color: String,
     content: Vec<BagContent>,
     file_fragment: String,
@@ -17,39 +19,45 @@ struct BagContent {
     color: String,
 }
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let arr_data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day07;
 
-    part1(&arr_data1);
-    println!("--------------------------------------------------");
-    part2(&arr_data1);
-    Ok(())
-}
+impl Solution for Day07 {
+    type Input = Vec<BagDefinition>;
+    type Answer1 = usize;
+    type Answer2 = Result<usize, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<Vec<BagDefinition>, String> {
-    let parsed = input
-        .split("\n")
+fn parse_data(input: String) -> Result<Vec<BagDefinition>, ParseError> {
+    let bags: Vec<BagDefinition> = lines(&input)
         .map(|file_fragment| {
-            if file_fragment.is_empty() {
-                return Err("Empty");
-            }
             // vibrant purple bags contain 3 shiny lavender bags, 1 mirrored gray bag, 4 muted bronze bags.
             // clear salmon bags contain 1 light yellow bag.
             let (bag_color, bag_content_str) = split_once(file_fragment, " bags contain")
-                .expect(format!("Should contain a bag def {:?}", file_fragment).as_str());
+                .ok_or_else(|| {
+                    ParseError::at(
+                        DAY,
+                        &input,
+                        file_fragment,
+                        "Should contain \" bags contain\"",
+                    )
+                })?;
 
             let content: Vec<BagContent> = bag_content_str
                 // ' 3 shiny lavender bags, 1 mirrored gray bag, 4 muted bronze bags.'
                 // ' 1 light yellow bag.'
-                .split(|c| c == '.' || c == ',')
+                .split(['.', ','])
                 // [" 3 shiny lavender bags"," 1 mirrored gray bag"," 4 muted bronze bags",""]
                 // [" 1 light yellow bag",""]
                 .map(|bag_content_def| bag_content_def.trim())
@@ -62,53 +70,42 @@ fn parse_data(input: String) -> Result<Vec<BagDefinition>, String> {
                 // ["3 shiny lavender","1 mirrored gray","4 muted bronze"]
                 // ["1 light yellow"]
                 .map(|bag_content_def| {
-                    let (count, color) = split_once(bag_content_def, " ").expect(
-                        format!(
-                            "Should be formatted as \"<count> <color>\" {:?}",
-                            bag_content_def
+                    let (count, color) = split_once(bag_content_def, " ").ok_or_else(|| {
+                        ParseError::at(
+                            DAY,
+                            &input,
+                            bag_content_def,
+                            "Should be formatted as \"<count> <color>\"",
                         )
-                        .as_str(),
-                    );
-                    return BagContent {
-                        count: count
-                            .parse::<usize>()
-                            .expect(format!("Should be a usize {:?}", count).as_str()),
-                        color,
-                    };
+                    })?;
+                    return Ok(BagContent {
+                        count: count.parse::<usize>().map_err(|_| {
+                            ParseError::at(DAY, &input, count, "Cannot parse as usize")
+                        })?,
+                        color: color.to_string(),
+                    });
                 })
-                .collect();
+                .collect::<Result<_, ParseError>>()?;
 
             return Ok(BagDefinition {
-                color: bag_color,
+                color: bag_color.to_string(),
                 content,
                 file_fragment: file_fragment.to_string(),
             });
         })
-        .filter(Result::is_ok)
-        .map(|group_form| group_form.expect("Should be a bag def"))
-        .collect();
-    return Ok(parsed);
-}
-
-fn split_once(str: &str, pattern: &str) -> Option<(String, String)> {
-    let mut split: Vec<String> = str.split(pattern).map(|s| s.to_string()).collect();
-    // println!("split {:?} with {:?} give {:?}", str, pattern, split);
-    return if split.len() >= 2 {
-        let mut second = split.pop().unwrap();
-        loop {
-            if split.len() == 1 {
-                break;
-            }
-            second = format!("{}{}{}", split.pop().unwrap(), pattern, second);
-        }
-        let first = split.pop().unwrap();
-        return Some((first, second));
-    } else {
-        None
-    };
+        .collect::<Result<_, ParseError>>()?;
+    if bags.is_empty() {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            &input[input.len()..],
+            "Should contain at least one bag rule",
+        ));
+    }
+    return Ok(bags);
 }
 
-fn part1(arr_data: &Vec<BagDefinition>) {
+fn part1(arr_data: &Vec<BagDefinition>) -> usize {
     let mut containing_shiny_gold: HashSet<String> = HashSet::new();
     containing_shiny_gold.insert("shiny gold".to_string());
     loop {
@@ -131,14 +128,11 @@ fn part1(arr_data: &Vec<BagDefinition>) {
             break;
         }
     }
-    println!(
-        "Part1: {:?}/{:?}",
-        containing_shiny_gold,
-        containing_shiny_gold.len()
-    );
+    // we need to exclude shiny gold bag itself
+    return containing_shiny_gold.len() - 1;
 }
 
-fn part2(arr_data: &Vec<BagDefinition>) {
+fn part2(arr_data: &Vec<BagDefinition>) -> Result<usize, String> {
     let mut result: Vec<&BagContent> = Vec::new();
     let mut pending: VecDeque<&BagContent> = VecDeque::new();
     let shiny_gold = BagContent {
@@ -147,29 +141,24 @@ fn part2(arr_data: &Vec<BagDefinition>) {
     };
     pending.push_back(&shiny_gold);
 
-    loop {
-        match pending.pop_front() {
-            Some(current) => {
-                result.push(current);
-                let bag_def: &BagDefinition = arr_data
-                    .iter()
-                    .find(|b| b.color == current.color)
-                    .expect(format!("Bag {:?} should exists.", current).as_str());
-                bag_def.content.iter().for_each(|bag_content| {
-                    for _ in (Range {
-                        start: 0,
-                        end: bag_content.count,
-                    }) {
-                        pending.push_back(bag_content);
-                    }
-                });
+    while let Some(current) = pending.pop_front() {
+        result.push(current);
+        let bag_def: &BagDefinition = arr_data
+            .iter()
+            .find(|b| b.color == current.color)
+            .ok_or_else(|| format!("Bag {} should have a rule", current.color))?;
+        bag_def.content.iter().for_each(|bag_content| {
+            for _ in (Range {
+                start: 0,
+                end: bag_content.count,
+            }) {
+                pending.push_back(bag_content);
             }
-            None => break,
-        }
+        });
     }
 
     // we need to exclude shiny gold bag itself
-    println!("Part2: {:?}", result.len() - 1);
+    return Ok(result.len() - 1);
 }
 
 #[cfg(test)]
@@ -191,9 +180,7 @@ mod tests {
                     count: 2,
                     color: "plaid green".to_string()
                 }]
-                .iter()
-                .cloned()
-                .collect(),
+                .to_vec(),
                 file_fragment: "clear aqua bags contain 2 plaid green bags.".to_string(),
             }
         );
@@ -209,7 +196,7 @@ mod tests {
             actual,
             BagDefinition {
                 color: "vibrant purple".to_string(),
-                content: [BagContent{ count: 3, color: "shiny lavender".to_string() },BagContent{ count: 1, color: "mirrored gray".to_string() },BagContent{ count: 4, color: "muted bronze".to_string() } ].iter().cloned().collect(),
+                content: [BagContent{ count: 3, color: "shiny lavender".to_string() },BagContent{ count: 1, color: "mirrored gray".to_string() },BagContent{ count: 4, color: "muted bronze".to_string() } ].to_vec(),
                 file_fragment: "vibrant purple bags contain 3 shiny lavender bags, 1 mirrored gray bag, 4 muted bronze bags.".to_string(),
             }
         );
@@ -230,11 +217,25 @@ mod tests {
                     count: 1,
                     color: "light yellow".to_string()
                 }]
-                .iter()
-                .cloned()
-                .collect(),
+                .to_vec(),
                 file_fragment: "clear salmon bags contain 1 light yellow bag.".to_string(),
             }
         );
     }
+
+    #[test]
+    fn it_should_reject_empty_input() {
+        let error = parse_data("".to_string()).unwrap_err();
+        assert_eq!(error.message, "Should contain at least one bag rule");
+    }
+
+    #[test]
+    fn it_should_report_a_bag_without_rule() {
+        let bags = parse_data("light red bags contain 1 shiny gold bag.".to_string()).unwrap();
+        assert_eq!(Day07::part1(&bags), 1);
+        assert_eq!(
+            Day07::part2(&bags),
+            Err("Bag shiny gold should have a rule".to_string())
+        );
+    }
 }
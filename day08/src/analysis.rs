@@ -0,0 +1,179 @@
+use std::collections::VecDeque;
+
+use crate::isa::Op;
+use crate::vm::{Stop, Vm};
+
+/**
+ The single NOP/JMP flip that makes a looping program end, `acc` is the accumulator at its end
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Repair {
+    pub pc: usize,
+    pub from: Op,
+    pub to: Op,
+    pub acc: i64,
+}
+
+/**
+ Instructions that can run after the one at `pc`, `program.len()` stands for the normal end of the
+ program (also reached by hlt). Jumps outside of the program are dropped.
+*/
+pub fn successors(program: &[Op], pc: usize) -> Vec<usize> {
+    let next = |offset: i64| -> Option<usize> {
+        let target = pc as i64 + offset;
+        if target < 0 || target > program.len() as i64 {
+            return None;
+        }
+        return Some(target as usize);
+    };
+    let targets = match program[pc] {
+        Op::NOP(_) | Op::ACC(_) | Op::ADD(_, _) | Op::SET(_, _) => vec![next(1)],
+        Op::JMP(arg) => vec![next(arg)],
+        // the register value is unknown statically: both branches may run
+        Op::JZ(_, arg) | Op::JNZ(_, arg) => vec![next(1), next(arg)],
+        Op::HLT => vec![Some(program.len())],
+    };
+    return targets.into_iter().flatten().collect();
+}
+
+/**
+ For each instruction (and the end, at index `program.len()`), whether a path of the control flow
+ graph leads to the end of the program. Linear: a single walk of the reversed graph from the end.
+*/
+pub fn reaches_end(program: &[Op]) -> Vec<bool> {
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); program.len() + 1];
+    for pc in 0..program.len() {
+        for successor in successors(program, pc) {
+            predecessors[successor].push(pc);
+        }
+    }
+    let mut res: Vec<bool> = vec![false; program.len() + 1];
+    res[program.len()] = true;
+    let mut queue: VecDeque<usize> = VecDeque::from(vec![program.len()]);
+    while let Some(pc) = queue.pop_front() {
+        for predecessor in &predecessors[pc] {
+            if !res[*predecessor] {
+                res[*predecessor] = true;
+                queue.push_back(*predecessor);
+            }
+        }
+    }
+    return res;
+}
+
+/**
+ Instructions of the infinite loop in execution order, starting with the first instruction that
+ runs twice. None when the program does not loop.
+*/
+pub fn loop_cycle(program: &[Op]) -> Option<Vec<usize>> {
+    let mut vm = Vm::new(program);
+    vm.enable_loop_detection();
+    vm.enable_trace();
+    let loop_start = match vm.run() {
+        Stop::Loop(pc) => pc,
+        _ => return None,
+    };
+    let trace = vm.trace();
+    let start = trace.iter().position(|entry| entry.pc == loop_start)?;
+    return Some(trace[start..].iter().map(|entry| entry.pc).collect());
+}
+
+/**
+ Find the NOP/JMP flip that makes the program end without trying them all.
+
+ Only the instructions run before the loop matter, and flipping one of them fixes the program when
+ its new successor can reach the end in the original graph: that path cannot go back through the
+ flipped instruction, otherwise the original program would have ended too. The candidate is still
+ run once to get its accumulator (and to double check programs with conditional jumps).
+*/
+pub fn repair(program: &[Op]) -> Option<Repair> {
+    let can_end = reaches_end(program);
+    let mut vm = Vm::new(program);
+    vm.enable_loop_detection();
+    vm.enable_trace();
+    if let Stop::Halted = vm.run() {
+        return None;
+    }
+    for entry in vm.trace() {
+        let (flipped, offset) = match entry.op {
+            Op::NOP(arg) => (Op::JMP(arg), arg),
+            Op::JMP(arg) => (Op::NOP(arg), 1),
+            _ => continue,
+        };
+        let next = entry.pc as i64 + offset;
+        if next < 0 || next > program.len() as i64 || !can_end[next as usize] {
+            continue;
+        }
+        let mut fixed_program: Vec<Op> = program.to_vec();
+        fixed_program[entry.pc] = flipped;
+        let mut fixed_vm = Vm::new(&fixed_program);
+        fixed_vm.enable_loop_detection();
+        if fixed_vm.run() == Stop::Halted {
+            return Some(Repair {
+                pc: entry.pc,
+                from: entry.op,
+                to: flipped,
+                acc: fixed_vm.acc(),
+            });
+        }
+    }
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program() -> Vec<Op> {
+        // nop +0, acc +1, jmp +4, acc +3, jmp -3, acc -99, acc +1, jmp -4, acc +6
+        return vec![
+            Op::NOP(0),
+            Op::ACC(1),
+            Op::JMP(4),
+            Op::ACC(3),
+            Op::JMP(-3),
+            Op::ACC(-99),
+            Op::ACC(1),
+            Op::JMP(-4),
+            Op::ACC(6),
+        ];
+    }
+
+    #[test]
+    fn it_should_find_instructions_reaching_the_end() {
+        assert_eq!(
+            reaches_end(&program()),
+            vec![false, false, false, false, false, false, false, false, true, true]
+        );
+        assert_eq!(
+            successors(&[Op::JZ(1, 2), Op::HLT, Op::JMP(-5)], 0),
+            vec![1, 2]
+        );
+        assert_eq!(
+            successors(&[Op::JZ(1, 2), Op::HLT, Op::JMP(-5)], 1),
+            vec![3]
+        );
+        assert_eq!(successors(&[Op::JZ(1, 2), Op::HLT, Op::JMP(-5)], 2), vec![]);
+    }
+
+    #[test]
+    fn it_should_report_loop_cycle() {
+        assert_eq!(loop_cycle(&program()), Some(vec![1, 2, 6, 7, 3, 4]));
+        assert_eq!(loop_cycle(&[Op::ACC(1), Op::HLT]), None);
+    }
+
+    #[test]
+    fn it_should_repair_program() {
+        assert_eq!(
+            repair(&program()),
+            Some(Repair {
+                pc: 7,
+                from: Op::JMP(-4),
+                to: Op::NOP(-4),
+                acc: 8,
+            })
+        );
+        assert_eq!(repair(&[Op::ACC(1)]), None);
+        assert_eq!(repair(&[Op::JMP(0), Op::JMP(0)]), None);
+    }
+}
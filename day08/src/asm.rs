@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use aoc_core::{split_once, ParseError};
+
+use crate::isa::{find_register, find_spec, Op, OperandKind, REGISTERS};
+use crate::DAY;
+
+/**
+ Assemble console source, one instruction per line:
+
+ ```text
+ # comments start with `#` or `;`
+ start: acc +1        ; a label names the next instruction
+        jnz r1 start  ; jump operands are offsets (+4, -3) or labels
+ end:   hlt
+ ```
+*/
+pub fn assemble(input: &str) -> Result<Vec<Op>, ParseError> {
+    // (op code spec, operands values, unresolved labels: (operand index, label))
+    let mut instructions = Vec::new();
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    for raw_line in input.split('\n') {
+        let mut line = raw_line.split(['#', ';']).next().unwrap_or_default().trim();
+        while let Some((label, rest)) = split_once(line, ":") {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(ParseError::at(DAY, input, label, "Invalid label"));
+            }
+            if labels.insert(label, instructions.len()).is_some() {
+                return Err(ParseError::at(DAY, input, label, "Duplicated label"));
+            }
+            line = rest.trim();
+        }
+        if line.is_empty() {
+            continue;
+        }
+        let words: Vec<&str> = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|word| !word.is_empty())
+            .collect();
+        if words.is_empty() {
+            return Err(ParseError::at(DAY, input, line, "Expected an instruction"));
+        }
+        let spec = find_spec(words[0]).ok_or_else(|| {
+            ParseError::at(DAY, input, words[0], "This operation is not recognized")
+        })?;
+        if words.len() - 1 != spec.operands.len() {
+            let message = format!("{} expects {} operands", spec.op_code, spec.operands.len());
+            return Err(ParseError::at(DAY, input, line, &message));
+        }
+        let mut values: Vec<i64> = Vec::new();
+        let mut unresolved: Vec<(usize, &str)> = Vec::new();
+        for (i, (kind, word)) in spec.operands.iter().zip(&words[1..]).enumerate() {
+            let value = match kind {
+                OperandKind::Register => find_register(word)
+                    .map(|register| register as i64)
+                    .ok_or_else(|| ParseError::at(DAY, input, word, "Unknown register"))?,
+                OperandKind::Value => parse_i64(input, word)?,
+                OperandKind::Offset if is_label(word) => {
+                    unresolved.push((i, word));
+                    0
+                }
+                OperandKind::Offset => parse_i64(input, word)?,
+            };
+            values.push(value);
+        }
+        instructions.push((spec, values, unresolved));
+    }
+
+    let mut program: Vec<Op> = Vec::new();
+    for (index, (spec, mut values, unresolved)) in instructions.into_iter().enumerate() {
+        for (i, label) in unresolved {
+            let target = labels
+                .get(label)
+                .ok_or_else(|| ParseError::at(DAY, input, label, "Unknown label"))?;
+            values[i] = *target as i64 - index as i64;
+        }
+        program.push((spec.build)(&values));
+    }
+    return Ok(program);
+}
+
+fn is_label(word: &str) -> bool {
+    return word.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+}
+
+fn parse_i64(input: &str, value: &str) -> Result<i64, ParseError> {
+    return value
+        .parse::<i64>()
+        .map_err(|_| ParseError::at(DAY, input, value, "Should be a valid i64"));
+}
+
+/**
+ Print a program back to source that assembles to the same program: every jump target inside
+ the program (or just after its end) gets a `l<index>` label
+*/
+pub fn disassemble(program: &[Op]) -> String {
+    let target = |index: usize, offset: i64| -> Option<usize> {
+        let target = index as i64 + offset;
+        if target < 0 || target > program.len() as i64 {
+            return None;
+        }
+        return Some(target as usize);
+    };
+    let mut targets: Vec<bool> = vec![false; program.len() + 1];
+    for (index, op) in program.iter().enumerate() {
+        for (kind, value) in op.spec().operands.iter().zip(op.operands()) {
+            if let (OperandKind::Offset, Some(t)) = (kind, target(index, value)) {
+                targets[t] = true;
+            }
+        }
+    }
+
+    let mut res = String::new();
+    for (index, is_target) in targets.iter().enumerate() {
+        if *is_target {
+            res += &format!("l{}:\n", index);
+        }
+        let op = match program.get(index) {
+            Some(op) => op,
+            None => break,
+        };
+        res += &format!("    {}", op.op_code());
+        for (kind, value) in op.spec().operands.iter().zip(op.operands()) {
+            let operand = match (kind, target(index, value)) {
+                (OperandKind::Register, _) => REGISTERS[value as usize].to_string(),
+                (OperandKind::Offset, Some(t)) => format!("l{}", t),
+                (OperandKind::Value, _) | (OperandKind::Offset, None) => format!("{:+}", value),
+            };
+            res += &format!(" {}", operand);
+        }
+        res += "\n";
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_assemble_labels_and_comments() {
+        let source = "# count down r1 from 3
+            set r1 +3
+            loop: add r1 -1   ; decrement
+            acc +2
+            jnz r1, loop
+            jmp end
+            NOP +0
+            end:
+            hlt";
+        assert_eq!(
+            assemble(source),
+            Ok(vec![
+                Op::SET(1, 3),
+                Op::ADD(1, -1),
+                Op::ACC(2),
+                Op::JNZ(1, -2),
+                Op::JMP(2),
+                Op::NOP(0),
+                Op::HLT,
+            ])
+        );
+    }
+
+    #[test]
+    fn it_should_reject_invalid_source() {
+        let error = assemble("acc +1\njmp nowhere\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.message, "Unknown label");
+        assert!(assemble("add r9 +1").is_err());
+        assert!(assemble("acc +1 +2").is_err());
+        assert!(assemble("a: nop +0\na: hlt").is_err());
+        let error = assemble("hlt\n , ,\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "Expected an instruction");
+    }
+
+    #[test]
+    fn it_should_round_trip_through_disassembler() {
+        let program = vec![
+            Op::NOP(0),
+            Op::ACC(1),
+            Op::JMP(4),
+            Op::ACC(3),
+            Op::JMP(-3),
+            Op::ACC(-99),
+            Op::SET(2, 7),
+            Op::JZ(2, -40),
+            Op::JMP(2),
+            Op::HLT,
+        ];
+        let source = disassemble(&program);
+        assert_eq!(
+            source,
+            "l0:
+    nop l0
+l1:
+    acc +1
+    jmp l6
+    acc +3
+    jmp l1
+    acc -99
+l6:
+    set r2 +7
+    jz r2 -40
+    jmp l10
+    hlt
+l10:
+"
+        );
+        assert_eq!(assemble(&source), Ok(program));
+    }
+}
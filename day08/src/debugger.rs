@@ -0,0 +1,254 @@
+use std::io::{BufRead, Write};
+
+use crate::analysis::{loop_cycle, repair};
+use crate::asm::disassemble;
+use crate::isa::{find_spec, Op, REGISTERS};
+use crate::vm::{Breakpoint, Stop, Vm};
+
+const HELP: &str = "Commands:
+    s, step [N]        run the next N instructions (default: 1)
+    c, continue        run until the end, a breakpoint, a loop or 1000000 instructions
+    b, break <pc|op>   stop before the instruction at pc, or before every op code (jmp...)
+    d, delete <pc|op>  remove a breakpoint
+    i, info            pc, registers and breakpoints
+    l, list [N]        the N instructions around the pc (default: 5)
+    t, trace           every instruction executed so far
+    disasm             the whole program as assembly source with labels
+    analyze            the loop of the program and the NOP/JMP flip that ends it
+    r, reset           restart the program, breakpoints are kept
+    h, help            this help
+    q, quit";
+
+/**
+ Line-based debugger reading commands from `input` and writing to `output` until `quit` or the
+ end of `input`
+*/
+pub fn repl<R: BufRead, W: Write>(program: &[Op], input: R, mut output: W) -> std::io::Result<()> {
+    let mut vm = Vm::new(program);
+    vm.enable_trace();
+    // without conditional jumps an instruction running twice means the program never ends
+    if !program.iter().any(|op| op.is_conditional_jump()) {
+        vm.enable_loop_detection();
+    }
+    writeln!(
+        output,
+        "{} instructions, `help` for the commands",
+        program.len()
+    )?;
+    write!(output, "(pc {}) ", vm.state().pc)?;
+    output.flush()?;
+    for line in input.lines() {
+        let line = line?;
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["q"] | ["quit"] => return Ok(()),
+            ["h"] | ["help"] => writeln!(output, "{}", HELP)?,
+            ["s"] | ["step"] => step(&mut vm, 1, &mut output)?,
+            ["s", n] | ["step", n] => match n.parse::<usize>() {
+                Ok(n) => step(&mut vm, n, &mut output)?,
+                Err(_) => writeln!(output, "Invalid step count {:?}", n)?,
+            },
+            ["c"] | ["continue"] => {
+                let stop = vm.run();
+                writeln!(output, "{}", describe(&vm, &stop))?;
+            }
+            ["b", target] | ["break", target] => match parse_breakpoint(target) {
+                Some(breakpoint) => {
+                    writeln!(output, "Breakpoint on {}", target)?;
+                    vm.add_breakpoint(breakpoint);
+                }
+                None => writeln!(output, "Invalid breakpoint {:?}", target)?,
+            },
+            ["d", target] | ["delete", target] => {
+                let removed = parse_breakpoint(target)
+                    .map(|breakpoint| vm.remove_breakpoint(&breakpoint))
+                    .unwrap_or(false);
+                if removed {
+                    writeln!(output, "Breakpoint on {} deleted", target)?;
+                } else {
+                    writeln!(output, "No breakpoint on {:?}", target)?;
+                }
+            }
+            ["i"] | ["info"] => {
+                let state = vm.state();
+                write!(output, "pc {}", state.pc)?;
+                for (name, value) in REGISTERS.iter().zip(state.registers.iter()) {
+                    write!(output, " {} {}", name, value)?;
+                }
+                writeln!(output)?;
+                for breakpoint in vm.breakpoints() {
+                    writeln!(output, "break {}", breakpoint)?;
+                }
+            }
+            ["l"] | ["list"] => list(&vm, 5, &mut output)?,
+            ["l", n] | ["list", n] => match n.parse::<usize>() {
+                Ok(n) => list(&vm, n, &mut output)?,
+                Err(_) => writeln!(output, "Invalid line count {:?}", n)?,
+            },
+            ["t"] | ["trace"] => {
+                for entry in vm.trace() {
+                    writeln!(
+                        output,
+                        "{:>5}: {:<10} acc {}",
+                        entry.pc, entry.op, entry.acc
+                    )?;
+                }
+            }
+            ["disasm"] => write!(output, "{}", disassemble(program))?,
+            ["analyze"] => analyze(program, &mut output)?,
+            ["r"] | ["reset"] => {
+                vm.reset();
+                writeln!(output, "Program restarted")?;
+            }
+            _ => writeln!(
+                output,
+                "Unknown command {:?}, `help` for the commands",
+                line
+            )?,
+        }
+        write!(output, "(pc {}) ", vm.state().pc)?;
+        output.flush()?;
+    }
+    return Ok(());
+}
+
+fn parse_breakpoint(target: &str) -> Option<Breakpoint> {
+    if let Ok(pc) = target.parse::<usize>() {
+        return Some(Breakpoint::Pc(pc));
+    }
+    return find_spec(target).map(|spec| Breakpoint::OpCode(spec.op_code.to_string()));
+}
+
+fn step<W: Write>(vm: &mut Vm, count: usize, output: &mut W) -> std::io::Result<()> {
+    for _ in 0..count {
+        match vm.step() {
+            Ok(entry) => writeln!(
+                output,
+                "{:>5}: {:<10} acc {}",
+                entry.pc, entry.op, entry.acc
+            )?,
+            Err(stop) => {
+                writeln!(output, "{}", describe(vm, &stop))?;
+                break;
+            }
+        }
+    }
+    return Ok(());
+}
+
+fn list<W: Write>(vm: &Vm, count: usize, output: &mut W) -> std::io::Result<()> {
+    let program = vm.program();
+    let pc = vm.state().pc;
+    let start = (pc - count as i64 / 2).max(0) as usize;
+    for (i, op) in program.iter().enumerate().skip(start).take(count) {
+        let marker = if i as i64 == pc { "=>" } else { "  " };
+        let visits = vm.state().visits[i];
+        writeln!(output, "{} {:>5}: {:<10} ({} runs)", marker, i, op, visits)?;
+    }
+    return Ok(());
+}
+
+fn analyze<W: Write>(program: &[Op], output: &mut W) -> std::io::Result<()> {
+    match loop_cycle(program) {
+        Some(cycle) => {
+            let pcs: Vec<String> = cycle.iter().map(|pc| pc.to_string()).collect();
+            writeln!(
+                output,
+                "Loop of {} instructions: {}",
+                cycle.len(),
+                pcs.join(" -> ")
+            )?;
+        }
+        None => writeln!(output, "No loop")?,
+    }
+    match repair(program) {
+        Some(fix) => writeln!(
+            output,
+            "Replace {} at {} with {}: ends with acc {}",
+            fix.from, fix.pc, fix.to, fix.acc
+        )?,
+        None => writeln!(output, "No NOP/JMP flip makes the program end")?,
+    }
+    return Ok(());
+}
+
+fn describe(vm: &Vm, stop: &Stop) -> String {
+    let acc = vm.acc();
+    return match stop {
+        Stop::Halted => format!("Program ended, acc {}", acc),
+        Stop::OutOfBounds(pc) => format!("Jumped out of the program to {}, acc {}", pc, acc),
+        Stop::Loop(pc) => format!("Loop detected: {} runs again, acc {}", pc, acc),
+        Stop::Breakpoint(pc) => {
+            format!("Breakpoint at {}: {}, acc {}", pc, vm.program()[*pc], acc)
+        }
+        Stop::StepLimit(pc) => {
+            format!(
+                "Paused at {} after {} steps, acc {}",
+                pc,
+                vm.step_limit(),
+                acc
+            )
+        }
+        Stop::InvalidRegister(pc) => {
+            format!(
+                "Unknown register at {}: {}, acc {}",
+                pc,
+                vm.program()[*pc],
+                acc
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_run_debugger_commands() {
+        let program = vec![Op::NOP(0), Op::ACC(1), Op::JMP(4), Op::ACC(3), Op::JMP(-3)];
+        let commands =
+            "step 2\nbreak jmp\ncontinue\ncontinue\ninfo\nreset\ncontinue\nanalyze\nquit\nstep\n";
+        let mut output: Vec<u8> = Vec::new();
+        repl(&program, commands.as_bytes(), &mut output).unwrap();
+        let actual = String::from_utf8(output).unwrap();
+        assert_eq!(
+            actual,
+            "5 instructions, `help` for the commands
+(pc 0)     0: nop +0     acc 0
+    1: acc +1     acc 1
+(pc 2) Breakpoint on jmp
+(pc 2) Breakpoint at 2: jmp +4, acc 1
+(pc 2) Jumped out of the program to 6, acc 1
+(pc 6) pc 6 acc 1 r1 0 r2 0 r3 0
+break jmp
+(pc 6) Program restarted
+(pc 0) Breakpoint at 2: jmp +4, acc 1
+(pc 2) No loop
+No NOP/JMP flip makes the program end
+(pc 2) "
+        );
+    }
+
+    #[test]
+    fn it_should_continue_through_counting_loops() {
+        let program = vec![
+            Op::SET(1, 3),
+            Op::ACC(2),
+            Op::ADD(1, -1),
+            Op::JNZ(1, -2),
+            Op::HLT,
+        ];
+        let mut output: Vec<u8> = Vec::new();
+        repl(&program, "continue\n".as_bytes(), &mut output).unwrap();
+        let actual = String::from_utf8(output).unwrap();
+        assert!(actual.contains("(pc 0) Program ended, acc 6\n"));
+
+        let program = vec![Op::ACC(1), Op::JNZ(0, -1)];
+        let mut output: Vec<u8> = Vec::new();
+        repl(&program, "continue\ninfo\n".as_bytes(), &mut output).unwrap();
+        let actual = String::from_utf8(output).unwrap();
+        assert!(actual.contains("(pc 0) Paused at 0 after 1000000 steps, acc 500000\n"));
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt;
+
+use self::OperandKind::*;
+
+/**
+ Registers of the console, the accumulator is the first one
+*/
+pub const REGISTERS: [&str; 4] = ["acc", "r1", "r2", "r3"];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    // OP(argument)
+    NOP(i64),
+    ACC(i64),
+    JMP(i64),
+    // OP(register, argument)
+    ADD(usize, i64),
+    SET(usize, i64),
+    JZ(usize, i64),
+    JNZ(usize, i64),
+    HLT,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OperandKind {
+    Register, // index in REGISTERS, written by name
+    Value,    // signed number: +4, -3, 12
+    Offset,   // relative jump: a signed number or a label
+}
+
+/**
+ How an op code is written: its operands and how to build the `Op` from their values.
+
+ A new instruction is a new `Op` variant, a line in `INSTRUCTION_SET` and its behaviour in the VM.
+*/
+pub struct OpSpec {
+    pub op_code: &'static str,
+    pub operands: &'static [OperandKind],
+    pub build: fn(&[i64]) -> Op,
+}
+
+pub const INSTRUCTION_SET: &[OpSpec] = &[
+    OpSpec {
+        op_code: "nop",
+        operands: &[Offset], // an offset as a nop can be fixed into a jmp
+        build: |args| Op::NOP(args[0]),
+    },
+    OpSpec {
+        op_code: "acc",
+        operands: &[Value],
+        build: |args| Op::ACC(args[0]),
+    },
+    OpSpec {
+        op_code: "jmp",
+        operands: &[Offset],
+        build: |args| Op::JMP(args[0]),
+    },
+    OpSpec {
+        op_code: "add",
+        operands: &[Register, Value],
+        build: |args| Op::ADD(args[0] as usize, args[1]),
+    },
+    OpSpec {
+        op_code: "set",
+        operands: &[Register, Value],
+        build: |args| Op::SET(args[0] as usize, args[1]),
+    },
+    OpSpec {
+        op_code: "jz",
+        operands: &[Register, Offset],
+        build: |args| Op::JZ(args[0] as usize, args[1]),
+    },
+    OpSpec {
+        op_code: "jnz",
+        operands: &[Register, Offset],
+        build: |args| Op::JNZ(args[0] as usize, args[1]),
+    },
+    OpSpec {
+        op_code: "hlt",
+        operands: &[],
+        build: |_| Op::HLT,
+    },
+];
+
+/**
+ Case insensitive lookup of an op code in `INSTRUCTION_SET`
+*/
+pub fn find_spec(op_code: &str) -> Option<&'static OpSpec> {
+    return INSTRUCTION_SET
+        .iter()
+        .find(|spec| spec.op_code.eq_ignore_ascii_case(op_code));
+}
+
+pub fn find_register(name: &str) -> Option<usize> {
+    return REGISTERS
+        .iter()
+        .position(|register| register.eq_ignore_ascii_case(name));
+}
+
+impl Op {
+    pub fn op_code(&self) -> &'static str {
+        return match self {
+            Op::NOP(_) => "nop",
+            Op::ACC(_) => "acc",
+            Op::JMP(_) => "jmp",
+            Op::ADD(_, _) => "add",
+            Op::SET(_, _) => "set",
+            Op::JZ(_, _) => "jz",
+            Op::JNZ(_, _) => "jnz",
+            Op::HLT => "hlt",
+        };
+    }
+
+    /**
+     A jump depending on a register: with one, running an instruction again is not a loop
+    */
+    pub fn is_conditional_jump(&self) -> bool {
+        return matches!(self, Op::JZ(_, _) | Op::JNZ(_, _));
+    }
+
+    /**
+     Index of the register the op reads or writes, None for ops without one
+    */
+    pub fn register(&self) -> Option<usize> {
+        return match *self {
+            Op::ADD(register, _)
+            | Op::SET(register, _)
+            | Op::JZ(register, _)
+            | Op::JNZ(register, _) => Some(register),
+            _ => None,
+        };
+    }
+
+    pub fn spec(&self) -> &'static OpSpec {
+        return find_spec(self.op_code()).expect("Every op should be in INSTRUCTION_SET");
+    }
+
+    /**
+     Operand values in the `OpSpec` order, registers as their index
+    */
+    pub fn operands(&self) -> Vec<i64> {
+        return match *self {
+            Op::NOP(arg) | Op::ACC(arg) | Op::JMP(arg) => vec![arg],
+            Op::ADD(register, arg)
+            | Op::SET(register, arg)
+            | Op::JZ(register, arg)
+            | Op::JNZ(register, arg) => vec![register as i64, arg],
+            Op::HLT => Vec::new(),
+        };
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut res = self.op_code().to_string();
+        for (kind, value) in self.spec().operands.iter().zip(self.operands()) {
+            match kind {
+                Register => match REGISTERS.get(value as usize) {
+                    Some(name) => res += &format!(" {}", name),
+                    None => res += &format!(" <register {}>", value),
+                },
+                Value | Offset => res += &format!(" {:+}", value),
+            }
+        }
+        // pad so `{:<10}` aligns the debugger columns
+        return f.pad(&res);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_build_every_op_from_its_spec() {
+        let ops = vec![
+            Op::NOP(-1),
+            Op::ACC(2),
+            Op::JMP(3),
+            Op::ADD(1, -4),
+            Op::SET(2, 5),
+            Op::JZ(3, -6),
+            Op::JNZ(0, 7),
+            Op::HLT,
+        ];
+        for op in ops {
+            assert_eq!((op.spec().build)(&op.operands()), op);
+        }
+        assert_eq!(format!("{}", Op::JNZ(1, -2)), "jnz r1 -2");
+        assert_eq!(format!("{}", Op::ADD(9, 1)), "add <register 9> +1");
+        assert_eq!(find_spec("JMP").map(|spec| spec.op_code), Some("jmp"));
+    }
+}
@@ -0,0 +1,124 @@
+use aoc_core::{ParseError, Solution};
+
+pub mod analysis;
+pub mod asm;
+pub mod debugger;
+pub mod isa;
+pub mod vm;
+
+pub use isa::Op;
+use vm::Vm;
+
+const DAY: u8 = 8;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Op>;
+    type Answer1 = i64;
+    type Answer2 = Result<i64, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<Op>, ParseError> {
+    let program = asm::assemble(&input)?;
+    if program.is_empty() {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            &input[input.len()..],
+            "Should contain at least one instruction",
+        ));
+    }
+    return Ok(program);
+}
+
+fn part1(arr_data: &Vec<Op>) -> i64 {
+    let mut vm = Vm::new(arr_data);
+    vm.enable_loop_detection();
+    vm.run();
+    return vm.acc();
+}
+
+fn part2(arr_data: &Vec<Op>) -> Result<i64, &'static str> {
+    return analysis::repair(arr_data)
+        .map(|fix| fix.acc)
+        .ok_or("No NOP/JMP flip makes the program end");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly_1() {
+        let parse_result_result = parse_data(
+            "nop +0
+            acc +1
+            jmp +4
+            acc +3
+            jmp -3
+            acc -99
+            acc +1
+            jmp -4
+            acc +6
+            "
+            .to_string(),
+        );
+        let actual = parse_result_result.unwrap();
+        let expected: Vec<Op> = vec![
+            Op::NOP(0),
+            Op::ACC(1),
+            Op::JMP(4),
+            Op::ACC(3),
+            Op::JMP(-3),
+            Op::ACC(-99),
+            Op::ACC(1),
+            Op::JMP(-4),
+            Op::ACC(6),
+        ];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_should_reject_unknown_operation() {
+        let actual = parse_data("nop +0\nacc +1\nmul +4\n".to_string());
+        assert_eq!(
+            actual,
+            Err(ParseError {
+                day: 8,
+                line: 3,
+                column: 1,
+                fragment: "mul".to_string(),
+                message: "This operation is not recognized".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_empty_program() {
+        let error = parse_data("# no code\n".to_string()).unwrap_err();
+        assert_eq!(error.message, "Should contain at least one instruction");
+    }
+
+    #[test]
+    fn it_should_report_a_program_without_repair() {
+        let program = parse_data("acc +1".to_string()).unwrap();
+        assert_eq!(Day08::part1(&program), 1);
+        assert_eq!(
+            Day08::part2(&program),
+            Err("No NOP/JMP flip makes the program end")
+        );
+    }
+}
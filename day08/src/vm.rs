@@ -0,0 +1,442 @@
+use std::fmt;
+
+use crate::isa::{Op, REGISTERS};
+
+/**
+ Execution state of a program: the program itself is never mutated so several runs can share it
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct State {
+    pub pc: i64,
+    pub registers: [i64; REGISTERS.len()], // registers[0] is the accumulator
+    pub visits: Vec<u32>,                  // executions count of each instruction
+    pub halted: bool,                      // a hlt instruction ran
+}
+
+impl State {
+    fn new(program_len: usize) -> State {
+        return State {
+            pc: 0,
+            registers: [0; REGISTERS.len()],
+            visits: vec![0; program_len],
+            halted: false,
+        };
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Breakpoint {
+    Pc(usize),
+    OpCode(String), // "nop", "acc", "jmp"...
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Breakpoint::Pc(pc) => write!(f, "{}", pc),
+            Breakpoint::OpCode(op_code) => write!(f, "{}", op_code),
+        };
+    }
+}
+
+/**
+ One executed instruction, `acc` is the accumulator after it ran
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TraceEntry {
+    pub pc: usize,
+    pub op: Op,
+    pub acc: i64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Stop {
+    Halted,                 // pc just after the last instruction, or a hlt instruction ran
+    OutOfBounds(i64),       // pc anywhere else outside the program
+    Loop(usize),            // the instruction at this pc was about to run a second time
+    Breakpoint(usize),      // a breakpoint matched the instruction at this pc, not run yet
+    StepLimit(usize),       // the run used all its steps before the instruction at this pc
+    InvalidRegister(usize), // the instruction at this pc names a register that does not exist
+}
+
+/**
+ Instructions run by a single `run` unless `set_step_limit` changes it, so a looping program
+ still ends
+*/
+pub const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+pub struct Vm<'a> {
+    program: &'a [Op],
+    state: State,
+    breakpoints: Vec<Breakpoint>,
+    resume_at: Option<usize>, // pc of the breakpoint the last run stopped at
+    loop_detection: bool,
+    step_limit: usize, // steps of a single run
+    trace: Option<Vec<TraceEntry>>,
+}
+
+impl<'a> Vm<'a> {
+    pub fn new(program: &'a [Op]) -> Vm<'a> {
+        return Vm {
+            program,
+            state: State::new(program.len()),
+            breakpoints: Vec::new(),
+            resume_at: None,
+            loop_detection: false,
+            step_limit: DEFAULT_STEP_LIMIT,
+            trace: None,
+        };
+    }
+
+    pub fn program(&self) -> &'a [Op] {
+        return self.program;
+    }
+
+    pub fn state(&self) -> &State {
+        return &self.state;
+    }
+
+    pub fn acc(&self) -> i64 {
+        return self.state.registers[0];
+    }
+
+    /**
+     Back to the first instruction, breakpoints are kept and the trace is cleared
+    */
+    pub fn reset(&mut self) {
+        self.state = State::new(self.program.len());
+        self.resume_at = None;
+        if let Some(trace) = &mut self.trace {
+            trace.clear();
+        }
+    }
+
+    /**
+     Index of the next instruction, None when the program halted or the pc is outside of it
+    */
+    pub fn current_pc(&self) -> Option<usize> {
+        return self.next_pc().ok();
+    }
+
+    /**
+     Index of the next instruction, or why there is none
+    */
+    fn next_pc(&self) -> Result<usize, Stop> {
+        let pc = self.state.pc;
+        if self.state.halted || pc == self.program.len() as i64 {
+            return Err(Stop::Halted);
+        }
+        if pc < 0 || pc as usize >= self.program.len() {
+            return Err(Stop::OutOfBounds(pc));
+        }
+        return Ok(pc as usize);
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) -> bool {
+        let count = self.breakpoints.len();
+        self.breakpoints.retain(|b| b != breakpoint);
+        return self.breakpoints.len() != count;
+    }
+
+    pub fn breakpoints(&self) -> &Vec<Breakpoint> {
+        return &self.breakpoints;
+    }
+
+    /**
+     Record every executed instruction from now on
+    */
+    pub fn enable_trace(&mut self) {
+        if self.trace.is_none() {
+            self.trace = Some(Vec::new());
+        }
+    }
+
+    /**
+     Stop runs before an instruction runs a second time. Without conditional jumps the program
+     would then run forever (part 1), with them this stops counting loops too.
+    */
+    pub fn enable_loop_detection(&mut self) {
+        self.loop_detection = true;
+    }
+
+    /**
+     Stop every run after `steps` instructions, `DEFAULT_STEP_LIMIT` until then
+    */
+    pub fn set_step_limit(&mut self, steps: usize) {
+        self.step_limit = steps;
+    }
+
+    pub fn step_limit(&self) -> usize {
+        return self.step_limit;
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        return self.trace.as_deref().unwrap_or(&[]);
+    }
+
+    /**
+     Run the next instruction whatever its visit count, the error tells why it could not run:
+     there is no next instruction or it names an unknown register
+    */
+    pub fn step(&mut self) -> Result<TraceEntry, Stop> {
+        let pc = self.next_pc()?;
+        let op = self.program[pc];
+        if op
+            .register()
+            .is_some_and(|register| register >= REGISTERS.len())
+        {
+            return Err(Stop::InvalidRegister(pc));
+        }
+        self.resume_at = None;
+        let registers = &mut self.state.registers;
+        self.state.pc += match op {
+            Op::NOP(_) => 1,
+            Op::ACC(arg) => {
+                registers[0] = registers[0].wrapping_add(arg);
+                1
+            }
+            Op::JMP(arg) => arg,
+            Op::ADD(register, arg) => {
+                registers[register] = registers[register].wrapping_add(arg);
+                1
+            }
+            Op::SET(register, arg) => {
+                registers[register] = arg;
+                1
+            }
+            Op::JZ(register, arg) => {
+                if registers[register] == 0 {
+                    arg
+                } else {
+                    1
+                }
+            }
+            Op::JNZ(register, arg) => {
+                if registers[register] != 0 {
+                    arg
+                } else {
+                    1
+                }
+            }
+            Op::HLT => {
+                self.state.halted = true;
+                0
+            }
+        };
+        self.state.visits[pc] += 1;
+        let entry = TraceEntry {
+            pc,
+            op,
+            acc: self.acc(),
+        };
+        if let Some(trace) = &mut self.trace {
+            trace.push(entry);
+        }
+        return Ok(entry);
+    }
+
+    /**
+     Run until the program ends, reaches a breakpoint, an invalid instruction or the step limit,
+     or loops when loop detection is enabled. A run resumed from the breakpoint it stopped at runs
+     that instruction first instead of stopping again.
+    */
+    pub fn run(&mut self) -> Stop {
+        let mut resume_at = self.resume_at.take();
+        let mut steps: usize = 0;
+        loop {
+            let pc = match self.next_pc() {
+                Ok(pc) => pc,
+                Err(stop) => return stop,
+            };
+            if self.loop_detection && self.state.visits[pc] > 0 {
+                return Stop::Loop(pc);
+            }
+            if resume_at != Some(pc) && self.is_breakpoint(pc) {
+                self.resume_at = Some(pc);
+                return Stop::Breakpoint(pc);
+            }
+            if self.step_limit == steps {
+                return Stop::StepLimit(pc);
+            }
+            resume_at = None;
+            steps += 1;
+            if let Err(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+
+    fn is_breakpoint(&self, pc: usize) -> bool {
+        return self.breakpoints.iter().any(|breakpoint| match breakpoint {
+            Breakpoint::Pc(b_pc) => *b_pc == pc,
+            Breakpoint::OpCode(op_code) => self.program[pc].op_code() == op_code,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program() -> Vec<Op> {
+        // nop +0, acc +1, jmp +4, acc +3, jmp -3, acc -99, acc +1, jmp -4, acc +6
+        return vec![
+            Op::NOP(0),
+            Op::ACC(1),
+            Op::JMP(4),
+            Op::ACC(3),
+            Op::JMP(-3),
+            Op::ACC(-99),
+            Op::ACC(1),
+            Op::JMP(-4),
+            Op::ACC(6),
+        ];
+    }
+
+    #[test]
+    fn it_should_stop_before_looping() {
+        let program = program();
+        let mut vm = Vm::new(&program);
+        vm.enable_loop_detection();
+        assert_eq!(vm.run(), Stop::Loop(1));
+        assert_eq!(vm.acc(), 5);
+        assert_eq!(vm.state().visits, vec![1, 1, 1, 1, 1, 0, 1, 1, 0]);
+    }
+
+    #[test]
+    fn it_should_halt_after_last_instruction() {
+        let mut program = program();
+        program[7] = Op::NOP(-4);
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Stop::Halted);
+        assert_eq!(vm.acc(), 8);
+    }
+
+    #[test]
+    fn it_should_stop_on_breakpoints() {
+        let program = program();
+        let mut vm = Vm::new(&program);
+        vm.enable_loop_detection();
+        vm.add_breakpoint(Breakpoint::Pc(6));
+        vm.add_breakpoint(Breakpoint::OpCode("jmp".to_string()));
+        assert_eq!(vm.run(), Stop::Breakpoint(2));
+        assert_eq!(vm.run(), Stop::Breakpoint(6));
+        assert_eq!(vm.run(), Stop::Breakpoint(7));
+        assert!(vm.remove_breakpoint(&Breakpoint::OpCode("jmp".to_string())));
+        assert_eq!(vm.run(), Stop::Loop(1));
+
+        // a fresh run stops on a breakpoint under the pc, and only resumes from it
+        assert!(vm.remove_breakpoint(&Breakpoint::Pc(6)));
+        vm.reset();
+        vm.add_breakpoint(Breakpoint::Pc(0));
+        assert_eq!(vm.run(), Stop::Breakpoint(0));
+        assert_eq!(vm.run(), Stop::Loop(1));
+        vm.reset();
+        vm.add_breakpoint(Breakpoint::Pc(4));
+        assert_eq!(vm.run(), Stop::Breakpoint(0));
+        assert_eq!(vm.run(), Stop::Breakpoint(4));
+        vm.step().unwrap();
+        vm.reset();
+        assert_eq!(vm.run(), Stop::Breakpoint(0));
+    }
+
+    #[test]
+    fn it_should_run_registers_and_conditional_jumps() {
+        // r1 = 3; do { acc += 2; r1 -= 1 } while r1 != 0; hlt; acc += 100
+        let program = vec![
+            Op::SET(1, 3),
+            Op::ACC(2),
+            Op::ADD(1, -1),
+            Op::JNZ(1, -2),
+            Op::HLT,
+            Op::ACC(100),
+        ];
+        let mut vm = Vm::new(&program);
+        while vm.step().is_ok() {}
+        assert_eq!(vm.acc(), 6);
+        assert_eq!(vm.state().registers, [6, 0, 0, 0]);
+        assert_eq!(vm.state().pc, 4);
+        vm.reset();
+        vm.add_breakpoint(Breakpoint::OpCode("hlt".to_string()));
+        assert_eq!(vm.run(), Stop::Breakpoint(4));
+        assert_eq!(vm.run(), Stop::Halted);
+        assert_eq!(vm.state().registers, [6, 0, 0, 0]);
+        assert_eq!(vm.state().visits, vec![1, 3, 3, 3, 1, 0]);
+
+        // only the visit once mode sees the counting loop as an infinite one
+        vm.reset();
+        vm.enable_loop_detection();
+        assert_eq!(vm.run(), Stop::Loop(1));
+    }
+
+    #[test]
+    fn it_should_end_a_looping_run_by_default() {
+        let program = program();
+        let mut vm = Vm::new(&program);
+        assert!(matches!(vm.run(), Stop::StepLimit(_)));
+        let steps: u32 = vm.state().visits.iter().sum();
+        assert_eq!(steps as usize, DEFAULT_STEP_LIMIT);
+    }
+
+    #[test]
+    fn it_should_not_run_an_unknown_register() {
+        let program = vec![Op::ACC(1), Op::ADD(9, 1), Op::HLT];
+        let mut vm = Vm::new(&program);
+        assert_eq!(vm.run(), Stop::InvalidRegister(1));
+        assert_eq!(vm.step(), Err(Stop::InvalidRegister(1)));
+        assert_eq!(vm.acc(), 1);
+        assert_eq!(vm.state().visits, vec![1, 0, 0]);
+    }
+
+    #[test]
+    fn it_should_stop_at_step_limit() {
+        let program = vec![Op::ADD(1, i64::MAX), Op::JMP(-1)];
+        let mut vm = Vm::new(&program);
+        vm.set_step_limit(5);
+        assert_eq!(vm.run(), Stop::StepLimit(1));
+        // registers wrap around
+        assert_eq!(vm.state().registers[1], i64::MAX.wrapping_mul(3));
+        assert_eq!(vm.run(), Stop::StepLimit(0));
+        assert_eq!(vm.state().visits, vec![5, 5]);
+    }
+
+    #[test]
+    fn it_should_trace_steps() {
+        let program = program();
+        let mut vm = Vm::new(&program);
+        vm.enable_trace();
+        vm.step().unwrap();
+        vm.step().unwrap();
+        vm.step().unwrap();
+        assert_eq!(
+            vm.trace(),
+            &[
+                TraceEntry {
+                    pc: 0,
+                    op: Op::NOP(0),
+                    acc: 0
+                },
+                TraceEntry {
+                    pc: 1,
+                    op: Op::ACC(1),
+                    acc: 1
+                },
+                TraceEntry {
+                    pc: 2,
+                    op: Op::JMP(4),
+                    acc: 1
+                },
+            ]
+        );
+        assert_eq!(vm.current_pc(), Some(6));
+        vm.reset();
+        assert_eq!(vm.current_pc(), Some(0));
+        assert!(vm.trace().is_empty());
+    }
+}
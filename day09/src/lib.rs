@@ -0,0 +1,152 @@
+use std::collections::VecDeque;
+
+use aoc_core::{parse_numbers, ParseError, Solution};
+
+const DAY: u8 = 9;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<u64>;
+    type Answer1 = Result<u64, &'static str>;
+    type Answer2 = Result<u64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+const PREAMBLE_SIZE: usize = 25;
+
+const NO_INVALID: &str = "Every number is the sum of two of the 25 previous ones";
+
+fn parse_data(input: String) -> Result<Vec<u64>, ParseError> {
+    let numbers = parse_numbers(DAY, &input)?;
+    if numbers.len() <= PREAMBLE_SIZE {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            &input[input.len()..],
+            "Should contain at least one number after the 25 numbers preamble",
+        ));
+    }
+    return Ok(numbers);
+}
+
+fn part1(arr_data: &Vec<u64>) -> Result<u64, &'static str> {
+    return find_first_invalid(arr_data).ok_or(NO_INVALID);
+}
+
+fn part2(arr_data: &Vec<u64>) -> Result<u64, String> {
+    let first_invalid: u64 = find_first_invalid(arr_data).ok_or(NO_INVALID)?;
+    let set = find_contiguous_set_summing_to(arr_data, first_invalid)?;
+    let min = set.iter().min().unwrap();
+    let max = set.iter().max().unwrap();
+    return Ok(min + max);
+}
+
+/**
+ The first number after the preamble that is not the sum of two of the 25 numbers before it
+*/
+fn find_first_invalid(arr_data: &Vec<u64>) -> Option<u64> {
+    let mut previous25: VecDeque<u64> = arr_data.iter().take(PREAMBLE_SIZE).copied().collect();
+    for current in arr_data.iter().skip(PREAMBLE_SIZE) {
+        if can_add_2_to_get(&previous25, *current) {
+            previous25.push_back(*current);
+            previous25.pop_front();
+        } else {
+            return Some(*current);
+        }
+    }
+    return None;
+}
+
+fn can_add_2_to_get(previous25: &VecDeque<u64>, n: u64) -> bool {
+    for i in 0..PREAMBLE_SIZE {
+        for j in 0..PREAMBLE_SIZE {
+            if i != j {
+                let one = previous25.get(i).unwrap_or_else(|| {
+                    panic!("Should be a valid position: {:?} {:?}", previous25, i)
+                });
+                let two = previous25.get(j).unwrap_or_else(|| {
+                    panic!("Should be a valid position: {:?} {:?}", previous25, j)
+                });
+                if one.checked_add(*two) == Some(n) {
+                    return true;
+                }
+            }
+        }
+    }
+    return false;
+}
+
+fn find_contiguous_set_summing_to(arr_data: &Vec<u64>, n: u64) -> Result<VecDeque<u64>, String> {
+    let mut current_set: VecDeque<u64> = VecDeque::new();
+    for current in arr_data.iter() {
+        current_set.push_back(*current);
+        loop {
+            // u128: a set of large numbers may overflow a u64 before it is trimmed
+            let sum: u128 = current_set.iter().map(|&number| number as u128).sum();
+            let n = n as u128;
+            if sum == n {
+                return Ok(current_set);
+            }
+            if sum > n {
+                current_set.pop_front();
+            }
+            if sum < n {
+                break;
+            }
+        }
+    }
+    return Err("Not found...".to_string());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preamble_then(numbers: &[u64]) -> String {
+        let mut data: Vec<u64> = (1..=25).collect();
+        data.extend_from_slice(numbers);
+        return data
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    #[test]
+    fn it_should_reject_input_without_number_to_check() {
+        for input in ["", "1", &preamble_then(&[])] {
+            let error = parse_data(input.to_string()).unwrap_err();
+            assert_eq!(
+                error.message,
+                "Should contain at least one number after the 25 numbers preamble"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_find_the_weakness() {
+        let data = Day09::parse(&preamble_then(&[26, 49, 100])).unwrap();
+        assert_eq!(Day09::part1(&data), Ok(100));
+        // 9 + 10 + ... + 16 = 100
+        assert_eq!(Day09::part2(&data), Ok(9 + 16));
+    }
+
+    #[test]
+    fn it_should_report_a_valid_list() {
+        let data = Day09::parse(&preamble_then(&[26, 49])).unwrap();
+        assert_eq!(Day09::part1(&data), Err(NO_INVALID));
+        assert_eq!(Day09::part2(&data), Err(NO_INVALID.to_string()));
+    }
+}
@@ -1,44 +1,35 @@
 // use std::collections::VecDeque;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::prelude::*;
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let arr_data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+use aoc_core::{parse_numbers, ParseError, Solution};
 
-    part1(&arr_data1);
-    println!("--------------------------------------------------");
-    part2(&arr_data1);
-    Ok(())
-}
+const DAY: u8 = 10;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<Vec<u64>, String> {
-    let parsed = input
-        .split("\n")
-        .map(|file_fragment| file_fragment.trim())
-        .map(|file_fragment| {
-            if file_fragment.is_empty() {
-                return Err("Empty");
-            }
-            return Ok(file_fragment
-                .parse::<u64>()
-                .expect(format!("Cannot be parse as u64: {:?}", file_fragment).as_str()));
-        })
-        .filter(Result::is_ok)
-        .map(|op| op.expect("Should be an operation"))
-        .collect();
-    return Ok(parsed);
+fn parse_data(input: String) -> Result<Vec<u64>, ParseError> {
+    return parse_numbers(DAY, &input);
 }
 
-fn part1(arr_data: &Vec<u64>) {
+fn part1(arr_data: &Vec<u64>) -> usize {
     let mut arr_data_sorted = arr_data.clone();
     arr_data_sorted.sort();
 
@@ -54,24 +45,18 @@ fn part1(arr_data: &Vec<u64>) {
     let one_diff_count = diff.iter().filter(|n| **n == 1).count();
     let three_diff_count = diff.iter().filter(|n| **n == 3).count();
 
-    println!(
-        "Part1: 1={:?} 3={:?} 1*3={:?}",
-        one_diff_count,
-        three_diff_count,
-        one_diff_count * three_diff_count
-    );
+    return one_diff_count * three_diff_count;
 }
 
-fn part2(arr_data: &Vec<u64>) {
+fn part2(arr_data: &Vec<u64>) -> u64 {
     let mut arr_data_sorted = arr_data.clone();
     arr_data_sorted.sort();
 
     let mut memo: HashMap<u64, u64> = HashMap::new();
-    let arrangements = 1 + count_possible_arrangements(0, &arr_data_sorted, &mut memo);
-
-    println!("Part2: {:?}", arrangements);
+    return 1 + count_possible_arrangements(0, &arr_data_sorted, &mut memo);
 }
 
+#[allow(clippy::unnecessary_unwrap)]
 fn count_possible_arrangements(
     previous: u64,
     sorted_data: &Vec<u64>,
@@ -80,7 +65,7 @@ fn count_possible_arrangements(
     if memo.contains_key(&previous) {
         return *memo.get(&previous).unwrap();
     }
-    let next1 = sorted_data.get(0);
+    let next1 = sorted_data.first();
     let next2 = sorted_data.get(1);
     let next3 = sorted_data.get(2);
 
@@ -108,5 +93,5 @@ fn count_possible_arrangements(
 }
 
 fn copy_skip(data: &Vec<u64>, skip: usize) -> Vec<u64> {
-    return data.iter().skip(skip).map(|n| *n).collect();
+    return data.iter().skip(skip).copied().collect();
 }
@@ -0,0 +1,58 @@
+use aoc_core::{Animation, Grid};
+
+use crate::{render, rounds, Position, Rules};
+
+const PALETTE: [[u8; 3]; 3] = [
+    [40, 40, 40],  // floor
+    [60, 160, 60], // empty seat
+    [200, 50, 50], // occupied seat
+];
+
+/**
+ Every round of the seating `rules` as text, each layout after a `Round N` title
+*/
+pub fn text_frames(data: &Grid<Position>, rules: Rules) -> String {
+    return rounds(data, rules)
+        .iter()
+        .enumerate()
+        .map(|(round, layout)| format!("Round {}\n{}", round, render(layout)))
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+/**
+ Every round of the seating `rules` as an animation, one frame per round
+*/
+pub fn animation(data: &Grid<Position>, rules: Rules) -> Animation {
+    let mut res = Animation::new(PALETTE.to_vec(), 20, 4);
+    for layout in rounds(data, rules) {
+        res.push(layout.map(|_, position| match position {
+            Position::Floor => 0,
+            Position::EmptySeat => 1,
+            Position::OccupiedSeat => 2,
+        }));
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::Solution;
+
+    use crate::{Day11, PART1_RULES, PART2_RULES};
+
+    #[test]
+    fn it_should_export_every_round() {
+        let seats = Day11::parse(include_str!("../input2.txt")).unwrap();
+        let text = text_frames(&seats, PART1_RULES);
+        assert_eq!(text.matches("Round ").count(), 6);
+        assert!(text.starts_with("Round 0\nL.LL.LL.LL\n"));
+        assert!(text.contains("\n\nRound 5\n#.#L.L#.##\n"));
+
+        let animation = animation(&seats, PART2_RULES);
+        assert_eq!(animation.frames.len(), 7);
+        assert_eq!(animation.frames[1].cells()[0], 2);
+    }
+}
@@ -0,0 +1,343 @@
+use aoc_core::{End, Grid, LifeRule, Network, ParseError, Solution, NEIGHBOURS8};
+
+pub mod frames;
+
+const DAY: u8 = 11;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Position {
+    Floor,
+    EmptySeat,
+    OccupiedSeat,
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Position>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Grid<Position>, ParseError> {
+    return Grid::parse(
+        DAY,
+        &input,
+        |c| match c {
+            '.' => Some(Position::Floor),
+            'L' => Some(Position::EmptySeat),
+            '#' => Some(Position::OccupiedSeat),
+            _ => None,
+        },
+        "Invalid character",
+    );
+}
+
+/**
+ Seat layout back to the puzzle notation
+*/
+pub fn render(state: &Grid<Position>) -> String {
+    return state.render(|pos| match pos {
+        Position::Floor => '.',
+        Position::EmptySeat => 'L',
+        Position::OccupiedSeat => '#',
+    });
+}
+
+/**
+ Which seats a passenger looks at to decide whether to move
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Visibility {
+    Adjacent,                 // the 8 cells around
+    LineOfSight,              // the first seat in each of the 8 directions, floor is transparent
+    LineOfSightWithin(usize), // the same, at most this many cells away
+    ManhattanRadius(usize),   // every seat at most this Manhattan distance away
+}
+
+/**
+ An empty seat becomes occupied when no visible seat is occupied, an occupied seat becomes empty
+ when at least `tolerance` visible seats are occupied
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rules {
+    pub visibility: Visibility,
+    pub tolerance: usize,
+}
+
+pub const PART1_RULES: Rules = Rules {
+    visibility: Visibility::Adjacent,
+    tolerance: 4,
+};
+
+pub const PART2_RULES: Rules = Rules {
+    visibility: Visibility::LineOfSight,
+    tolerance: 5,
+};
+
+/**
+ How a simulation ended: on `End::FixedPoint` the layout is stable and `rounds` is the number of
+ rounds that changed something to reach it. Some rules never settle, on `End::Cycle` the layout
+ is the first one repeating an older round, reached after `rounds` rounds.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulation {
+    pub layout: Grid<Position>,
+    pub rounds: usize,
+    pub end: End,
+}
+
+fn part1(data: &Grid<Position>) -> usize {
+    return count_occupied(&simulate(data, PART1_RULES).layout);
+}
+
+fn part2(data: &Grid<Position>) -> usize {
+    return count_occupied(&simulate(data, PART2_RULES).layout);
+}
+
+fn count_occupied(state: &Grid<Position>) -> usize {
+    return state
+        .cells()
+        .iter()
+        .filter(|pos| **pos == Position::OccupiedSeat)
+        .count();
+}
+
+/**
+ Run the seating `rules` until nobody moves anymore, or until the layouts start repeating
+*/
+pub fn simulate(data: &Grid<Position>, rules: Rules) -> Simulation {
+    return SeatMap::new(data, rules.visibility).simulate(rules.tolerance);
+}
+
+/**
+ Seat layout of every round with the seating `rules`, from `data` to the layout where `simulate`
+ ends
+*/
+pub fn rounds(data: &Grid<Position>, rules: Rules) -> Vec<Grid<Position>> {
+    return SeatMap::new(data, rules.visibility).rounds(rules.tolerance);
+}
+
+/**
+ The seats of a layout and the seats each one sees, computed once as the floor never changes
+*/
+pub struct SeatMap<'a> {
+    data: &'a Grid<Position>,
+    seats: Vec<(usize, usize)>, // position of each seat, by seat index
+    visible: Vec<Vec<usize>>,   // indexes of the seats seen from each seat
+}
+
+impl<'a> SeatMap<'a> {
+    pub fn new(data: &'a Grid<Position>, visibility: Visibility) -> SeatMap<'a> {
+        let seats: Vec<(usize, usize)> = data
+            .positions()
+            .filter(|p| data[*p] != Position::Floor)
+            .collect();
+        let mut index: Grid<usize> = data.map(|_, _| usize::MAX);
+        for (i, seat) in seats.iter().enumerate() {
+            index[*seat] = i;
+        }
+        let visible = seats
+            .iter()
+            .map(|(x, y)| {
+                return visible_seats(data, visibility, *x, *y)
+                    .iter()
+                    .map(|seat| index[*seat])
+                    .collect();
+            })
+            .collect();
+        return SeatMap {
+            data,
+            seats,
+            visible,
+        };
+    }
+
+    /**
+     The seating rules as a cellular automaton on the seats, a live cell being an occupied seat:
+     an empty seat is born on 0 visible occupied seats, an occupied seat survives below the
+     tolerance
+    */
+    fn network(&self, tolerance: usize) -> Network {
+        let rule = LifeRule {
+            birth: vec![0],
+            survival: (0..tolerance).collect(),
+        };
+        return Network::new(rule, self.visible.clone());
+    }
+
+    fn occupied(&self) -> Vec<bool> {
+        return self
+            .seats
+            .iter()
+            .map(|seat| self.data[*seat] == Position::OccupiedSeat)
+            .collect();
+    }
+
+    /**
+     Seat layout with the `occupied` seats (by seat index), every other seat empty
+    */
+    fn layout(&self, occupied: &[bool]) -> Grid<Position> {
+        let mut res = self.data.clone();
+        for (seat, is_occupied) in self.seats.iter().zip(occupied) {
+            res[*seat] = if *is_occupied {
+                Position::OccupiedSeat
+            } else {
+                Position::EmptySeat
+            };
+        }
+        return res;
+    }
+
+    pub fn simulate(&self, tolerance: usize) -> Simulation {
+        let outcome = self.network(tolerance).run(self.occupied(), None);
+        return Simulation {
+            layout: self.layout(&outcome.alive),
+            rounds: outcome.generations,
+            end: outcome.end,
+        };
+    }
+
+    pub fn rounds(&self, tolerance: usize) -> Vec<Grid<Position>> {
+        let network = self.network(tolerance);
+        let outcome = network.run(self.occupied(), None);
+        return network
+            .generations(self.occupied())
+            .take(outcome.generations + 1)
+            .map(|occupied| self.layout(&occupied))
+            .collect();
+    }
+}
+
+/**
+ Seats seen from `(x, y)` with the `visibility` policy. Every policy is symmetric: `a` sees `b`
+ exactly when `b` sees `a`.
+*/
+fn visible_seats(
+    state: &Grid<Position>,
+    visibility: Visibility,
+    x: usize,
+    y: usize,
+) -> Vec<(usize, usize)> {
+    let is_seat = |p: &(usize, usize)| state[*p] != Position::Floor;
+    let first_seat = |max_distance: usize| -> Vec<(usize, usize)> {
+        return NEIGHBOURS8
+            .iter()
+            .filter_map(|direction| {
+                return state.ray(x, y, *direction).take(max_distance).find(is_seat);
+            })
+            .collect();
+    };
+    return match visibility {
+        Visibility::Adjacent => state.neighbours8(x, y).filter(is_seat).collect(),
+        Visibility::LineOfSight => first_seat(usize::MAX),
+        Visibility::LineOfSightWithin(max_distance) => first_seat(max_distance),
+        Visibility::ManhattanRadius(radius) => {
+            let (min_x, min_y) = (x.saturating_sub(radius), y.saturating_sub(radius));
+            let max_x = (x + radius).min(state.width().saturating_sub(1));
+            let max_y = (y + radius).min(state.height().saturating_sub(1));
+            let mut res: Vec<(usize, usize)> = Vec::new();
+            for ny in min_y..=max_y {
+                for nx in min_x..=max_x {
+                    let distance = nx.abs_diff(x) + ny.abs_diff(y);
+                    if distance > 0 && distance <= radius && is_seat(&(nx, ny)) {
+                        res.push((nx, ny));
+                    }
+                }
+            }
+            res
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_solve_example() {
+        let seats = Day11::parse(include_str!("../input2.txt")).unwrap();
+        assert_eq!(Day11::part1(&seats), 37);
+        assert_eq!(Day11::part2(&seats), 26);
+    }
+
+    #[test]
+    fn it_should_render_seat_layout() {
+        let input = include_str!("../input2.txt");
+        let seats = Day11::parse(input).unwrap();
+        assert_eq!(render(&seats), input.replace('\r', ""));
+        let simulation = simulate(&seats, PART1_RULES);
+        assert_eq!(simulation.rounds, 5);
+        let last_round = render(&simulation.layout);
+        assert_eq!(last_round.lines().next(), Some("#.#L.L#.##"));
+    }
+
+    #[test]
+    fn it_should_apply_visibility_policies() {
+        let seats = Day11::parse(".L.L..L\nL......\n...#...\n").unwrap();
+        let seen = |visibility: Visibility| -> Vec<(usize, usize)> {
+            let mut res = visible_seats(&seats, visibility, 3, 2);
+            res.sort_unstable();
+            return res;
+        };
+        assert_eq!(seen(Visibility::Adjacent), vec![]);
+        assert_eq!(seen(Visibility::LineOfSight), vec![(1, 0), (3, 0)]);
+        assert_eq!(seen(Visibility::LineOfSightWithin(1)), vec![]);
+        assert_eq!(seen(Visibility::LineOfSightWithin(2)), vec![(1, 0), (3, 0)]);
+        assert_eq!(seen(Visibility::ManhattanRadius(3)), vec![(3, 0)]);
+        assert_eq!(
+            seen(Visibility::ManhattanRadius(4)),
+            vec![(0, 1), (1, 0), (3, 0)]
+        );
+
+        let seats = Day11::parse(include_str!("../input2.txt")).unwrap();
+        let simulation = simulate(&seats, PART2_RULES);
+        assert_eq!(
+            (count_occupied(&simulation.layout), simulation.rounds),
+            (26, 6)
+        );
+        let rules = Rules {
+            visibility: Visibility::ManhattanRadius(2),
+            tolerance: 8,
+        };
+        assert_eq!(
+            rounds(&seats, rules).len(),
+            simulate(&seats, rules).rounds + 1
+        );
+    }
+
+    #[test]
+    fn it_should_stop_oscillating_rules() {
+        let seats = Day11::parse("LL").unwrap();
+        let rules = Rules {
+            visibility: Visibility::Adjacent,
+            tolerance: 1,
+        };
+        // both seats get occupied together, then both see an occupied seat and leave
+        let simulation = simulate(&seats, rules);
+        assert_eq!(
+            simulation.end,
+            End::Cycle {
+                start: 0,
+                length: 2
+            }
+        );
+        assert_eq!(simulation.rounds, 2);
+        assert_eq!(render(&simulation.layout), "LL\n");
+        let layouts: Vec<String> = rounds(&seats, rules).iter().map(render).collect();
+        assert_eq!(layouts, vec!["LL\n", "##\n", "LL\n"]);
+        assert_eq!(simulate(&seats, PART1_RULES).end, End::FixedPoint);
+    }
+}
@@ -0,0 +1,231 @@
+use aoc_core::{lines, quarter_turns, Heading, ParseError, Solution, Vec2};
+
+pub mod route;
+
+const DAY: u8 = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Move {
+    Towards(Heading, i64),
+    Turn(u8), // counterclockwise quarter turns, from 0 to 3
+    Forward(i64),
+}
+
+/**
+ An interpreter of the navigation instructions
+*/
+pub trait Navigation {
+    fn apply(&mut self, m: Move);
+
+    fn position(&self) -> Vec2;
+
+    /**
+     Absolute position of the waypoint, if the interpretation has one
+    */
+    fn waypoint(&self) -> Option<Vec2> {
+        return None;
+    }
+}
+
+/**
+ Part 1 interpretation: the ship itself moves and turns
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ship {
+    pub position: Vec2,
+    pub heading: Heading,
+}
+
+impl Ship {
+    pub fn new(heading: Heading) -> Ship {
+        return Ship {
+            position: Vec2::ZERO,
+            heading,
+        };
+    }
+}
+
+impl Navigation for Ship {
+    fn apply(&mut self, m: Move) {
+        match m {
+            Move::Towards(heading, distance) => self.position += heading.vector() * distance,
+            Move::Turn(quarters) => self.heading = self.heading.rotate_quarters(quarters as i64),
+            Move::Forward(distance) => self.position += self.heading.vector() * distance,
+        }
+    }
+
+    fn position(&self) -> Vec2 {
+        return self.position;
+    }
+}
+
+/**
+ Part 2 interpretation: the instructions move and rotate a waypoint around the ship, the ship
+ only moves forward to it
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WaypointShip {
+    pub position: Vec2,
+    pub waypoint: Vec2, // relative to the ship
+}
+
+impl WaypointShip {
+    pub fn new(waypoint: Vec2) -> WaypointShip {
+        return WaypointShip {
+            position: Vec2::ZERO,
+            waypoint,
+        };
+    }
+}
+
+impl Navigation for WaypointShip {
+    fn apply(&mut self, m: Move) {
+        match m {
+            Move::Towards(heading, distance) => self.waypoint += heading.vector() * distance,
+            Move::Turn(quarters) => self.waypoint = self.waypoint.rotate_quarters(quarters as i64),
+            Move::Forward(times) => self.position += self.waypoint * times,
+        }
+    }
+
+    fn position(&self) -> Vec2 {
+        return self.position;
+    }
+
+    fn waypoint(&self) -> Option<Vec2> {
+        return Some(self.position + self.waypoint);
+    }
+}
+
+/**
+ Apply every move to `navigation` in order
+*/
+pub fn navigate<N: Navigation>(mut navigation: N, moves: &[Move]) -> N {
+    for m in moves {
+        navigation.apply(*m);
+    }
+    return navigation;
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Move>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<Move>, ParseError> {
+    return lines(&input)
+        .map(|file_fragment| {
+            if !file_fragment.is_char_boundary(1) {
+                return Err(ParseError::at(
+                    DAY,
+                    &input,
+                    file_fragment,
+                    "Not a valid move",
+                ));
+            }
+            let (direction, distance_str) = file_fragment.split_at(1);
+            let distance = distance_str
+                .parse::<i64>()
+                .map_err(|_| ParseError::at(DAY, &input, distance_str, "Should be a number"))?;
+            // a right turn is the remaining quarters of a left one
+            let turn = |clockwise: bool| {
+                let quarters = quarter_turns(distance)
+                    .map_err(|err| ParseError::at(DAY, &input, distance_str, &err.to_string()))?;
+                if clockwise {
+                    return Ok(Move::Turn((4 - quarters) % 4));
+                }
+                return Ok(Move::Turn(quarters));
+            };
+            return match direction {
+                "N" => Ok(Move::Towards(Heading::North, distance)),
+                "S" => Ok(Move::Towards(Heading::South, distance)),
+                "E" => Ok(Move::Towards(Heading::East, distance)),
+                "W" => Ok(Move::Towards(Heading::West, distance)),
+                "L" => turn(false),
+                "R" => turn(true),
+                "F" => Ok(Move::Forward(distance)),
+                any => Err(ParseError::at(DAY, &input, any, "Not a valid direction")),
+            };
+        })
+        .collect();
+}
+
+fn part1(data: &Vec<Move>) -> i64 {
+    return navigate(Ship::new(Heading::East), data)
+        .position
+        .manhattan();
+}
+
+fn part2(data: &Vec<Move>) -> i64 {
+    return navigate(WaypointShip::new(Vec2::new(10, 1)), data)
+        .position
+        .manhattan();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn turn(heading: Heading, instruction: &str) -> Heading {
+        let mut ship = Ship::new(heading);
+        for m in parse_data(instruction.to_string()).unwrap() {
+            ship.apply(m);
+        }
+        return ship.heading;
+    }
+
+    #[test]
+    fn it_should_compute_new_heading() {
+        assert_eq!(turn(Heading::East, "R0"), Heading::East);
+        assert_eq!(turn(Heading::East, "R360"), Heading::East);
+        assert_eq!(turn(Heading::East, "R90"), Heading::South);
+        assert_eq!(turn(Heading::East, "L90"), Heading::North);
+        assert_eq!(turn(Heading::East, "R180"), Heading::West);
+        assert_eq!(turn(Heading::East, "L180"), Heading::West);
+        assert_eq!(turn(Heading::North, "R90"), Heading::East);
+        assert_eq!(turn(Heading::North, "L90"), Heading::West);
+        assert_eq!(turn(Heading::North, "L270\nR180"), Heading::West);
+    }
+
+    #[test]
+    fn it_should_rotate_waypoint() {
+        let rotate = |instruction: &str| -> Vec2 {
+            let moves = parse_data(instruction.to_string()).unwrap();
+            return navigate(WaypointShip::new(Vec2::new(10, 4)), &moves).waypoint;
+        };
+        assert_eq!(rotate("R90"), Vec2::new(4, -10));
+        assert_eq!(rotate("R180"), Vec2::new(-10, -4));
+        assert_eq!(rotate("R270"), Vec2::new(-4, 10));
+        assert_eq!(rotate("R360"), Vec2::new(10, 4));
+        assert_eq!(rotate("L90"), Vec2::new(-4, 10));
+        assert_eq!(rotate("L270"), Vec2::new(4, -10));
+    }
+
+    #[test]
+    fn it_should_solve_example() {
+        let moves = Day12::parse("F10\nN3\nF7\nR90\nF11\n").unwrap();
+        assert_eq!(Day12::part1(&moves), 25);
+        assert_eq!(Day12::part2(&moves), 286);
+    }
+
+    #[test]
+    fn it_should_reject_non_right_angle_turns() {
+        let error = Day12::parse("F10\nR45\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "45 degrees is not a right angle turn");
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+use aoc_core::Vec2;
+
+use crate::{Move, Navigation};
+
+/**
+ Every position of a navigation, from the start to the end, one per move
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    pub positions: Vec<Vec2>,
+    pub waypoints: Vec<Vec2>, // absolute positions, empty when navigating without a waypoint
+}
+
+/**
+ Route statistics, distances being Manhattan distances
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub max_distance: i64, // from the origin
+    pub travelled: i64,
+    pub bounds: (Vec2, Vec2), // lowest then highest coordinates of the ship
+}
+
+/**
+ Apply every move to `navigation` like `navigate`, recording the route
+*/
+pub fn trace<N: Navigation>(mut navigation: N, moves: &[Move]) -> Route {
+    let mut res = Route {
+        positions: vec![navigation.position()],
+        waypoints: navigation.waypoint().into_iter().collect(),
+    };
+    for m in moves {
+        navigation.apply(*m);
+        res.positions.push(navigation.position());
+        res.waypoints.extend(navigation.waypoint());
+    }
+    return res;
+}
+
+impl Route {
+    pub fn stats(&self) -> Stats {
+        return Stats {
+            max_distance: self
+                .positions
+                .iter()
+                .map(|p| p.manhattan())
+                .max()
+                .unwrap_or(0),
+            travelled: self
+                .positions
+                .windows(2)
+                .map(|step| (step[1] - step[0]).manhattan())
+                .sum(),
+            bounds: bounds(&self.positions),
+        };
+    }
+
+    /**
+     The ship path (and the waypoint trail) as SVG polylines, north being up
+    */
+    pub fn to_svg(&self) -> String {
+        let all: Vec<Vec2> = self
+            .positions
+            .iter()
+            .chain(&self.waypoints)
+            .copied()
+            .collect();
+        let (min, max) = bounds(&all);
+        // a margin of 5% around the route, at least 1 unit
+        let margin = ((max - min).chebyshev() / 20).max(1);
+        let polyline = |points: &[Vec2], color: &str| -> String {
+            let coordinates: Vec<String> =
+                points.iter().map(|p| format!("{},{}", p.x, -p.y)).collect();
+            return format!(
+                "  <polyline points=\"{}\" fill=\"none\" stroke=\"{}\" \
+                 stroke-width=\"{}\" />\n",
+                coordinates.join(" "),
+                color,
+                margin as f64 / 5.0
+            );
+        };
+        let mut res = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n",
+            min.x - margin,
+            -max.y - margin,
+            max.x - min.x + 2 * margin,
+            max.y - min.y + 2 * margin
+        );
+        if !self.waypoints.is_empty() {
+            res.push_str(&polyline(&self.waypoints, "orange"));
+        }
+        res.push_str(&polyline(&self.positions, "navy"));
+        res.push_str("</svg>\n");
+        return res;
+    }
+}
+
+fn bounds(points: &[Vec2]) -> (Vec2, Vec2) {
+    let min = |axis: fn(&Vec2) -> i64| points.iter().map(axis).min().unwrap_or(0);
+    let max = |axis: fn(&Vec2) -> i64| points.iter().map(axis).max().unwrap_or(0);
+    return (
+        Vec2::new(min(|p| p.x), min(|p| p.y)),
+        Vec2::new(max(|p| p.x), max(|p| p.y)),
+    );
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (min, max) = self.bounds;
+        writeln!(f, "Max distance from the origin: {}", self.max_distance)?;
+        writeln!(f, "Distance travelled: {}", self.travelled)?;
+        return writeln!(
+            f,
+            "Bounding box: ({}, {}) to ({}, {})",
+            min.x, min.y, max.x, max.y
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::{Heading, Solution};
+
+    use crate::{Day12, Ship, WaypointShip};
+
+    #[test]
+    fn it_should_trace_routes() {
+        let moves = Day12::parse("F10\nN3\nF7\nR90\nF11\n").unwrap();
+        let route = trace(Ship::new(Heading::East), &moves);
+        assert_eq!(route.positions.len(), 6);
+        assert_eq!(route.positions[5], Vec2::new(17, -8));
+        assert!(route.waypoints.is_empty());
+        assert_eq!(
+            route.stats(),
+            Stats {
+                max_distance: 25,
+                travelled: 31,
+                bounds: (Vec2::new(0, -8), Vec2::new(17, 3)),
+            }
+        );
+
+        let route = trace(WaypointShip::new(Vec2::new(10, 1)), &moves);
+        assert_eq!(route.waypoints[0], Vec2::new(10, 1));
+        assert_eq!(route.waypoints[5], Vec2::new(218, -82));
+        assert_eq!(route.stats().max_distance, 286);
+        let svg = route.to_svg();
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.contains("points=\"0,0 100,-10 100,-10 170,-38 170,-38 214,72\""));
+        assert_eq!(svg.matches("<polyline").count(), 2);
+    }
+}
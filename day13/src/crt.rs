@@ -0,0 +1,279 @@
+use std::fmt;
+
+use aoc_core::Answer;
+
+/**
+ A natural number, stored in a `u128` until it overflows
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub enum Natural {
+    Small(u128),
+    Big(BigUint),
+}
+
+impl Natural {
+    pub fn to_u128(&self) -> Option<u128> {
+        return match self {
+            Natural::Small(n) => Some(*n),
+            Natural::Big(n) => n.to_u128(),
+        };
+    }
+
+    fn rem_small(&self, modulus: u64) -> u64 {
+        return match self {
+            Natural::Small(n) => (n % modulus as u128) as u64,
+            Natural::Big(n) => n.div_rem_small(modulus).1,
+        };
+    }
+
+    /**
+     `self + other * factor`, moving to a big integer on overflow
+    */
+    pub fn add_product(&self, other: &Natural, factor: u64) -> Natural {
+        if let (Natural::Small(a), Natural::Small(b)) = (self, other) {
+            if let Some(n) = b
+                .checked_mul(factor as u128)
+                .and_then(|product| product.checked_add(*a))
+            {
+                return Natural::Small(n);
+            }
+        }
+        return Natural::Big(self.to_big().add(&other.to_big().mul_small(factor)));
+    }
+
+    fn to_big(&self) -> BigUint {
+        return match self {
+            Natural::Small(n) => BigUint::from_u128(*n),
+            Natural::Big(n) => n.clone(),
+        };
+    }
+}
+
+impl fmt::Display for Natural {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            Natural::Small(n) => write!(f, "{}", n),
+            Natural::Big(n) => write!(f, "{}", n),
+        };
+    }
+}
+
+impl Answer for Natural {
+    fn render(&self) -> Result<String, String> {
+        return Ok(self.to_string());
+    }
+}
+
+/**
+ An arbitrarily large natural number, as 32 bits limbs from the least significant one. Only the
+ operations the solver needs are implemented.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct BigUint {
+    limbs: Vec<u32>, // without trailing zeros, so 0 has no limb
+}
+
+impl BigUint {
+    pub fn from_u128(mut n: u128) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::new();
+        while n > 0 {
+            limbs.push(n as u32);
+            n >>= 32;
+        }
+        return BigUint { limbs };
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.limbs.len() > 4 {
+            return None;
+        }
+        return Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |n, limb| (n << 32) | *limb as u128),
+        );
+    }
+
+    fn normalized(mut limbs: Vec<u32>) -> BigUint {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        return BigUint { limbs };
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::with_capacity(self.limbs.len().max(other.limbs.len()) + 1);
+        let mut carry: u64 = 0;
+        for i in 0..self.limbs.len().max(other.limbs.len()) {
+            let sum = carry
+                + *self.limbs.get(i).unwrap_or(&0) as u64
+                + *other.limbs.get(i).unwrap_or(&0) as u64;
+            limbs.push(sum as u32);
+            carry = sum >> 32;
+        }
+        limbs.push(carry as u32);
+        return BigUint::normalized(limbs);
+    }
+
+    pub fn mul_small(&self, factor: u64) -> BigUint {
+        let mut limbs: Vec<u32> = Vec::with_capacity(self.limbs.len() + 2);
+        let mut carry: u128 = 0;
+        for limb in &self.limbs {
+            let product = *limb as u128 * factor as u128 + carry;
+            limbs.push(product as u32);
+            carry = product >> 32;
+        }
+        while carry > 0 {
+            limbs.push(carry as u32);
+            carry >>= 32;
+        }
+        return BigUint::normalized(limbs);
+    }
+
+    /**
+     Quotient and remainder of the division by `divisor`, which must not be 0
+    */
+    pub fn div_rem_small(&self, divisor: u64) -> (BigUint, u64) {
+        let mut limbs: Vec<u32> = vec![0; self.limbs.len()];
+        let mut rem: u128 = 0;
+        for (i, limb) in self.limbs.iter().enumerate().rev() {
+            let current = (rem << 32) | *limb as u128;
+            limbs[i] = (current / divisor as u128) as u32;
+            rem = current % divisor as u128;
+        }
+        return (BigUint::normalized(limbs), rem as u64);
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // 9 decimal digits at a time, from the least significant ones
+        let mut chunks: Vec<u64> = Vec::new();
+        let mut n = self.clone();
+        while !n.limbs.is_empty() {
+            let (quotient, rem) = n.div_rem_small(1_000_000_000);
+            chunks.push(rem);
+            n = quotient;
+        }
+        let mut chunks = chunks.iter().rev();
+        write!(f, "{}", chunks.next().unwrap_or(&0))?;
+        for chunk in chunks {
+            write!(f, "{:09}", chunk)?;
+        }
+        return Ok(());
+    }
+}
+
+/**
+ Solve the system of `x ≡ residue (mod modulus)` congruences, the moduli don't have to be
+ coprime.
+
+ Returns the smallest non-negative solution and the least common multiple of the moduli (every
+ solution is the first one plus a multiple of it), None when the congruences contradict each
+ other or a modulus is 0.
+*/
+pub fn solve(congruences: &[(i64, u64)]) -> Option<(Natural, Natural)> {
+    // x ≡ residue (mod modulus) for every congruence merged so far
+    let mut residue = Natural::Small(0);
+    let mut modulus = Natural::Small(1);
+    for (a, n) in congruences {
+        if *n == 0 {
+            return None;
+        }
+        let a = (*a as i128).rem_euclid(*n as i128) as u64;
+        let (k, factor) = merge_step(residue.rem_small(*n), modulus.rem_small(*n), a, *n)?;
+        residue = residue.add_product(&modulus, k);
+        modulus = Natural::Small(0).add_product(&modulus, factor);
+    }
+    return Some((residue, modulus));
+}
+
+/**
+ Merge `x ≡ residue (mod modulus)` with `x ≡ a (mod n)`, knowing `residue` and `modulus` modulo
+ n only: the merged solution is `residue + k * modulus` modulo `modulus * factor`. Returns
+ `(k, factor)`, None when the congruences disagree modulo gcd(modulus, n).
+*/
+fn merge_step(residue: u64, modulus: u64, a: u64, n: u64) -> Option<(u64, u64)> {
+    // gcd(modulus, n) = gcd(modulus mod n, n), 0 mod n included
+    let (gcd, inverse, _) = bezout(modulus as i128, n as i128);
+    let gcd = gcd.unsigned_abs() as u64;
+    let difference = ((a as u128 + n as u128 - residue as u128) % n as u128) as u64;
+    if !difference.is_multiple_of(gcd) {
+        return None;
+    }
+    let factor = n / gcd;
+    // modulus / gcd * inverse ≡ 1 (mod factor)
+    let inverse = inverse.rem_euclid(factor as i128) as u128;
+    let k = ((difference / gcd) as u128 * inverse % factor as u128) as u64;
+    return Some((k, factor));
+}
+
+fn bezout(a: i128, b: i128) -> (i128, i128, i128) {
+    let mut r = a;
+    let mut r2 = b;
+    let mut u = 1;
+    let mut v = 0;
+    let mut u2 = 0;
+    let mut v2 = 1;
+    while r2 != 0 {
+        let q = r / r2;
+        let rs = r;
+        let us = u;
+        let vs = v;
+        r = r2;
+        u = u2;
+        v = v2;
+        r2 = rs - q * r2;
+        u2 = us - q * u2;
+        v2 = vs - q * v2;
+    }
+    return (r, u, v);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_compute_bezout() {
+        assert_eq!(bezout(66, 17), (1, 8, -31));
+        assert_eq!(bezout(102, 11), (1, 4, -37));
+        assert_eq!(bezout(187, 66), (11, -1, 3));
+    }
+
+    #[test]
+    fn it_should_solve_congruences() {
+        assert_eq!(
+            solve(&[(2, 3), (3, 5), (2, 7)]),
+            Some((Natural::Small(23), Natural::Small(105)))
+        );
+        // 6 and 4 are not coprime: x ≡ 0 (mod 2) for both
+        assert_eq!(
+            solve(&[(2, 6), (0, 4)]),
+            Some((Natural::Small(8), Natural::Small(12)))
+        );
+        assert_eq!(solve(&[(1, 6), (0, 4)]), None);
+        assert_eq!(solve(&[(-1, 7), (13, 7)]).unwrap().0.to_u128(), Some(6));
+        assert_eq!(solve(&[(-1, 7), (5, 7)]), None);
+        assert_eq!(solve(&[]).unwrap().1, Natural::Small(1));
+        assert_eq!(solve(&[(2, 3), (0, 0)]), None);
+    }
+
+    #[test]
+    fn it_should_fall_back_to_big_integers() {
+        // 3 consecutive numbers, the odd ones being coprime: their product overflows a u128
+        let congruences = [(1, u64::MAX - 2), (-2, u64::MAX - 1), (3, u64::MAX)];
+        let (residue, modulus) = solve(&congruences).unwrap();
+        assert!(matches!(modulus, Natural::Big(_)));
+        assert_eq!(modulus.to_u128(), None);
+        assert_eq!(residue.rem_small(u64::MAX - 2), 1);
+        assert_eq!(residue.rem_small(u64::MAX - 1), u64::MAX - 3);
+        assert_eq!(residue.rem_small(u64::MAX), 3);
+        assert_eq!(
+            BigUint::from_u128(u128::MAX).mul_small(1000).to_string(),
+            "340282366920938463463374607431768211455000"
+        );
+        assert_eq!(BigUint::from_u128(0).to_string(), "0");
+    }
+}
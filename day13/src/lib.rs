@@ -0,0 +1,218 @@
+use aoc_core::{lines, ParseError, Solution};
+
+use crt::Natural;
+use schedule::Schedule;
+
+pub mod crt;
+pub mod schedule;
+
+const DAY: u8 = 13;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Infos {
+    estimate_min_departure: u64,
+    bus: Vec<Bus>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Bus {
+    bus_id: u64,
+    ignored: bool,
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Infos;
+    type Answer1 = u64;
+    type Answer2 = Result<Natural, &'static str>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Infos, ParseError> {
+    let split: Vec<&str> = lines(&input).collect();
+    if split.len() != 2 {
+        let fragment = split.get(2).copied().unwrap_or(&input[input.len()..]);
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            fragment,
+            "Should contain exactly 2 lines: the departure then the bus ids",
+        ));
+    }
+    let estimate_min_departure = split[0]
+        .parse::<u64>()
+        .map_err(|_| ParseError::at(DAY, &input, split[0], "Should be a number"))?;
+    let bus: Vec<Bus> = split[1]
+        .split(',')
+        .map(|bus_id| bus_id.trim())
+        .filter(|bus_id| !bus_id.is_empty())
+        .map(|bus_id| {
+            if bus_id == "x" {
+                return Ok(Bus {
+                    bus_id: 0,
+                    ignored: true,
+                });
+            }
+            return match bus_id.parse::<u64>() {
+                Ok(0) => Err(ParseError::at(
+                    DAY,
+                    &input,
+                    bus_id,
+                    "A bus id should not be 0",
+                )),
+                Ok(bus_id) => Ok(Bus {
+                    bus_id,
+                    ignored: false,
+                }),
+                Err(_) => Err(ParseError::at(
+                    DAY,
+                    &input,
+                    bus_id,
+                    "Should be a number or x",
+                )),
+            };
+        })
+        .collect::<Result<_, ParseError>>()?;
+    if bus.iter().all(|bus| bus.ignored) {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            split[1],
+            "Should contain at least one bus id",
+        ));
+    }
+    let parsed = Infos {
+        estimate_min_departure,
+        bus,
+    };
+    return Ok(parsed);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct BusDeparture {
+    bus_id: u64,
+    next: u64,
+}
+
+fn part1(data: &Infos) -> u64 {
+    let bus_time_arrival: Vec<BusDeparture> = data
+        .bus
+        .iter()
+        .filter(|bus| !bus.ignored)
+        .map(|bus| {
+            let mut time = 0;
+            while time < data.estimate_min_departure {
+                time += bus.bus_id;
+            }
+            return BusDeparture {
+                bus_id: bus.bus_id,
+                next: time,
+            };
+        })
+        .collect();
+    let next_bus = bus_time_arrival.iter().min_by_key(|bus| bus.next).unwrap();
+    return next_bus.bus_id * (next_bus.next - data.estimate_min_departure);
+}
+
+fn part2(data: &Infos) -> Result<Natural, &'static str> {
+    return earliest_timestamp(data).ok_or("The buses never depart one minute after another");
+}
+
+/**
+ The first timestamp `t` where every bus departs at `t + index`, its index in the list:
+ `t + index ≡ 0 (mod bus_id)`. None when the bus ids make it impossible.
+*/
+fn earliest_timestamp(data: &Infos) -> Option<Natural> {
+    let schedule = Schedule::new(data);
+    return schedule
+        .alignments(&schedule.notes_pattern())
+        .map(|alignments| alignments.first);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_compute_earliest_1() {
+        let infos: Infos = parse_data("0\n7,13,x,x,59,x,31,19".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(1068781)));
+    }
+
+    #[test]
+    fn it_should_compute_earliest_2() {
+        let infos: Infos = parse_data("0\n17,x,13,19".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(3417)));
+    }
+
+    #[test]
+    fn it_should_compute_earliest_3() {
+        let infos: Infos = parse_data("0\n67,7,59,61".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(754018)));
+    }
+
+    #[test]
+    fn it_should_compute_earliest_4() {
+        let infos: Infos = parse_data("0\n67,x,7,59,61".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(779210)));
+    }
+
+    #[test]
+    fn it_should_compute_earliest_5() {
+        let infos: Infos = parse_data("0\n67,7,x,59,61".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(1261476)));
+    }
+
+    #[test]
+    fn it_should_compute_earliest_6() {
+        let infos: Infos = parse_data("0\n1789,37,47,1889".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(1202161486)));
+    }
+
+    #[test]
+    fn it_should_not_find_timestamp_for_inconsistent_buses() {
+        // 4 and 6 both depart on even minutes only: never one minute apart
+        let infos: Infos = parse_data("0\n4,6".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), None);
+        assert_eq!(
+            Day13::part2(&infos),
+            Err("The buses never depart one minute after another")
+        );
+        let infos: Infos = parse_data("0\n4,x,6".to_string()).unwrap();
+        assert_eq!(earliest_timestamp(&infos), Some(Natural::Small(4)));
+    }
+
+    #[test]
+    fn it_should_reject_bus_id_0() {
+        let error = parse_data("939\n7,0,x".to_string()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "A bus id should not be 0");
+        assert!(parse_data("939\n7,b".to_string()).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_a_list_without_bus() {
+        let error = parse_data("0\nx,x".to_string()).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "Should contain at least one bus id");
+    }
+
+    #[test]
+    fn it_should_solve_example() {
+        let infos = Day13::parse("939\n7,13,x,x,59,x,31,19").unwrap();
+        assert_eq!(Day13::part1(&infos), 295);
+        assert_eq!(Day13::part2(&infos), Ok(Natural::Small(1068781)));
+    }
+}
@@ -0,0 +1,259 @@
+use crate::crt::{self, Natural};
+use crate::Infos;
+
+/**
+ The buses in service, each one departing at every multiple of its id: never 0, the parser
+ rejects it
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schedule {
+    buses: Vec<(usize, u64)>, // (index in the notes, bus id)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Departure {
+    pub time: u64,
+    pub bus_id: u64,
+}
+
+/**
+ Every timestamp matching a pattern: `first`, then every `period` minutes
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alignments {
+    pub first: Natural,
+    pub period: Natural,
+}
+
+impl Alignments {
+    /**
+     The `k`-th matching timestamp, from 0
+    */
+    pub fn nth(&self, k: u64) -> Natural {
+        return self.first.add_product(&self.period, k);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Natural> + '_ {
+        return (0..).map(move |k| self.nth(k));
+    }
+}
+
+impl Schedule {
+    pub fn new(infos: &Infos) -> Schedule {
+        return Schedule {
+            buses: infos
+                .bus
+                .iter()
+                .enumerate()
+                .filter(|(_, bus)| !bus.ignored)
+                .map(|(index, bus)| (index, bus.bus_id))
+                .collect(),
+        };
+    }
+
+    pub fn bus_ids(&self) -> Vec<u64> {
+        return self.buses.iter().map(|(_, bus_id)| *bus_id).collect();
+    }
+
+    /**
+     The pattern of the puzzle: every bus departs its index in the notes minutes after `t`, as
+     (bus id, offset) pairs
+    */
+    pub fn notes_pattern(&self) -> Vec<(u64, i64)> {
+        return self
+            .buses
+            .iter()
+            .map(|(index, bus_id)| (*bus_id, *index as i64))
+            .collect();
+    }
+
+    /**
+     Every departure from `from` (included) to `to` (excluded), by time then by bus id
+    */
+    pub fn departures(&self, from: u64, to: u64) -> Vec<Departure> {
+        let mut res: Vec<Departure> = self
+            .timetable(from, to)
+            .into_iter()
+            .flat_map(|(bus_id, times)| {
+                return times
+                    .into_iter()
+                    .map(move |time| Departure { time, bus_id });
+            })
+            .collect();
+        res.sort_by_key(|departure| (departure.time, departure.bus_id));
+        return res;
+    }
+
+    /**
+     The departure times of each bus from `from` (included) to `to` (excluded), in the notes
+     order. A bus has no departure after `from` when the next one would not fit in a `u64`.
+    */
+    pub fn timetable(&self, from: u64, to: u64) -> Vec<(u64, Vec<u64>)> {
+        return self
+            .buses
+            .iter()
+            .map(|(_, bus_id)| {
+                let times = match from.div_ceil(*bus_id).checked_mul(*bus_id) {
+                    Some(first) => (first..to).step_by(*bus_id as usize).collect(),
+                    None => Vec::new(),
+                };
+                return (*bus_id, times);
+            })
+            .collect();
+    }
+
+    /**
+     Timestamps `t` where each bus of the `pattern` departs at `t + offset`, None if it never
+     happens. Bus ids that are not in service are accepted too.
+    */
+    pub fn alignments(&self, pattern: &[(u64, i64)]) -> Option<Alignments> {
+        let congruences: Vec<(i64, u64)> = pattern
+            .iter()
+            .map(|(bus_id, offset)| (departure_residue(*bus_id, *offset), *bus_id))
+            .collect();
+        return crt::solve(&congruences).map(|(first, period)| Alignments { first, period });
+    }
+
+    /**
+     The largest set of buses in service of the `pattern` that can depart at their offsets
+     together, in the pattern order. The earliest buses in the pattern win among sets of the
+     same size.
+
+     Congruences can be solved together when every two of them can, so this is the largest
+     clique of the compatible pairs: searched exhaustively, patterns are a few buses long.
+    */
+    pub fn satisfiable_buses(&self, pattern: &[(u64, i64)]) -> Vec<u64> {
+        let candidates: Vec<(u64, i64)> = pattern
+            .iter()
+            .filter(|(bus_id, _)| self.buses.iter().any(|(_, id)| id == bus_id))
+            .copied()
+            .collect();
+        let compatible: Vec<Vec<bool>> = candidates
+            .iter()
+            .map(|a| {
+                return candidates
+                    .iter()
+                    .map(|b| self.alignments(&[*a, *b]).is_some())
+                    .collect();
+            })
+            .collect();
+        let mut best: Vec<usize> = Vec::new();
+        largest_clique(&compatible, 0, &mut Vec::new(), &mut best);
+        return best.iter().map(|i| candidates[*i].0).collect();
+    }
+}
+
+/**
+ `-offset` modulo `bus_id` as a residue the solver accepts: `-i64::MIN` does not fit in an `i64`
+*/
+fn departure_residue(bus_id: u64, offset: i64) -> i64 {
+    if bus_id == 0 {
+        return 0; // no departure at all, the solver rejects it
+    }
+    let residue = -(offset as i128 % bus_id as i128);
+    if residue > i64::MAX as i128 {
+        return (residue - bus_id as i128) as i64;
+    }
+    return residue as i64;
+}
+
+/**
+ Extend `current` (a clique of `compatible`) with vertices from `next`, keeping in `best` the
+ first largest clique found
+*/
+fn largest_clique(
+    compatible: &[Vec<bool>],
+    next: usize,
+    current: &mut Vec<usize>,
+    best: &mut Vec<usize>,
+) {
+    if current.len() > best.len() {
+        *best = current.clone();
+    }
+    for vertex in next..compatible.len() {
+        if current.len() + compatible.len() - vertex <= best.len() {
+            return; // even with every remaining vertex it would not be larger
+        }
+        if current.iter().all(|other| compatible[*other][vertex]) {
+            current.push(vertex);
+            largest_clique(compatible, vertex + 1, current, best);
+            current.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::Solution;
+
+    use crate::Day13;
+
+    #[test]
+    fn it_should_list_departures() {
+        let schedule = Schedule::new(&Day13::parse("939\n7,13,x,x,59,x,31,19").unwrap());
+        assert_eq!(schedule.bus_ids(), vec![7, 13, 59, 31, 19]);
+        assert_eq!(
+            schedule.departures(939, 950),
+            vec![
+                Departure {
+                    time: 944,
+                    bus_id: 59
+                },
+                Departure {
+                    time: 945,
+                    bus_id: 7
+                },
+                Departure {
+                    time: 949,
+                    bus_id: 13
+                }
+            ]
+        );
+        assert_eq!(schedule.timetable(0, 15)[0], (7, vec![0, 7, 14]));
+        assert_eq!(schedule.timetable(939, 960)[1], (13, vec![949]));
+        // the last multiple of 7 is u64::MAX - 1, the one of 13 is u64::MAX - 2
+        let late = schedule.timetable(u64::MAX - 1, u64::MAX);
+        assert_eq!(late[0], (7, vec![u64::MAX - 1]));
+        assert_eq!(late[1], (13, vec![]));
+    }
+
+    #[test]
+    fn it_should_find_alignments() {
+        let schedule = Schedule::new(&Day13::parse("939\n7,13,x,x,59,x,31,19").unwrap());
+        let alignments = schedule.alignments(&schedule.notes_pattern()).unwrap();
+        assert_eq!(alignments.first, Natural::Small(1068781));
+        assert_eq!(alignments.period, Natural::Small(7 * 13 * 59 * 31 * 19));
+        assert_eq!(alignments.nth(2), Natural::Small(1068781 + 2 * 3162341));
+        assert_eq!(alignments.iter().nth(1), Some(Natural::Small(4231122)));
+
+        // 7 and 13 one minute apart, then 7 again 7 minutes later
+        let custom = schedule.alignments(&[(13, 0), (7, 1), (7, 8)]).unwrap();
+        assert_eq!(custom.first, Natural::Small(13));
+        assert_eq!(schedule.alignments(&[(7, 0), (7, 1)]), None);
+        assert_eq!(schedule.alignments(&[(7, 0), (0, 1)]), None);
+        let extreme = schedule.alignments(&[(7, i64::MIN), (u64::MAX, i64::MIN)]);
+        assert!(extreme.is_some());
+    }
+
+    #[test]
+    fn it_should_pick_satisfiable_buses() {
+        let schedule = Schedule::new(&Day13::parse("0\n4,6,9,x,5,10").unwrap());
+        // 4 and 6 both depart on even minutes, so not one minute apart
+        assert_eq!(
+            schedule.satisfiable_buses(&[(4, 0), (6, 1), (9, 3), (5, 2), (11, 0)]),
+            vec![4, 9, 5]
+        );
+        assert_eq!(
+            schedule.satisfiable_buses(&[(6, 1), (4, 0), (9, 4)]),
+            vec![6, 9]
+        );
+        // 6 is odd minutes, 4 and 10 even ones: keeping the first bus is not the largest set
+        assert_eq!(
+            schedule.satisfiable_buses(&[(6, 1), (4, 0), (10, 0)]),
+            vec![4, 10]
+        );
+        assert_eq!(schedule.satisfiable_buses(&[(7, 0), (11, 0)]), vec![]);
+    }
+}
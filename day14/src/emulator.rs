@@ -0,0 +1,311 @@
+use std::fmt;
+
+use crate::mask::Mask;
+use crate::memory::{AddressPattern, FloatingMemory};
+use crate::Op;
+
+/**
+ How `mem[address] = value` uses the mask
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Decoder {
+    V1, // the mask applies to the value
+    V2, // the mask applies to the address, floating bits included
+}
+
+/**
+ A traced `mem[address] = value` instruction
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Write {
+    pub op: usize, // index in the program
+    pub mask: Mask,
+    pub address: u64,
+    pub value: u64,
+    pub stored: u64, // the value actually written
+    pub addresses: AddressPattern,
+}
+
+impl fmt::Display for Write {
+    /**
+     The written addresses as their pattern, X for the floating bits
+    */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(
+            f,
+            "#{} mem[{}] = {} with mask {}: {} at {} ({} address(es))",
+            self.op,
+            self.address,
+            self.value,
+            self.mask,
+            self.stored,
+            self.addresses,
+            self.addresses.count()
+        );
+    }
+}
+
+/**
+ Runs a docking program, keeping the memory and optionally a trace of every write
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct Emulator {
+    decoder: Decoder,
+    mask: Mask,
+    memory: FloatingMemory,
+    trace: Option<Vec<Write>>,
+}
+
+impl Emulator {
+    pub fn new(decoder: Decoder) -> Emulator {
+        return Emulator {
+            decoder,
+            mask: Mask::default(),
+            memory: FloatingMemory::new(),
+            trace: None,
+        };
+    }
+
+    pub fn with_trace(decoder: Decoder) -> Emulator {
+        return Emulator {
+            trace: Some(Vec::new()),
+            ..Emulator::new(decoder)
+        };
+    }
+
+    /**
+     Execute every instruction of `program` in order
+    */
+    pub fn run(&mut self, program: &[Op]) {
+        for (index, op) in program.iter().enumerate() {
+            self.execute(index, *op);
+        }
+    }
+
+    /**
+     Execute `op`, `index` being its position in the program (for the trace only)
+    */
+    pub fn execute(&mut self, index: usize, op: Op) {
+        let (address, value) = match op {
+            Op::Mask(mask) => {
+                self.mask = mask;
+                return;
+            }
+            Op::Mem(address, value) => (address, value),
+        };
+        let (addresses, stored) = match self.decoder {
+            Decoder::V1 => (
+                AddressPattern::new(address, 0),
+                self.mask.apply_value(value),
+            ),
+            Decoder::V2 => (self.mask.address_pattern(address), value),
+        };
+        self.memory.write(addresses, stored);
+        if let Some(trace) = &mut self.trace {
+            trace.push(Write {
+                op: index,
+                mask: self.mask,
+                address,
+                value,
+                stored,
+                addresses,
+            });
+        }
+    }
+
+    pub fn decoder(&self) -> Decoder {
+        return self.decoder;
+    }
+
+    pub fn memory(&self) -> &FloatingMemory {
+        return &self.memory;
+    }
+
+    /**
+     Every write so far, empty when the emulator does not trace
+    */
+    pub fn trace(&self) -> &[Write] {
+        return self.trace.as_deref().unwrap_or(&[]);
+    }
+
+    /**
+     The memory as JSON: every region as an address pattern in the mask notation (X for the
+     floating bits) with its value, by increasing addresses
+    */
+    pub fn to_json(&self) -> String {
+        let mut regions = self.memory.regions().to_vec();
+        regions.sort_by_key(|(pattern, _)| (pattern.fixed, pattern.wildcard));
+        let rows: Vec<String> = regions
+            .iter()
+            .map(|(pattern, value)| {
+                format!(
+                    "    {{\"addresses\": \"{}\", \"count\": {}, \"value\": {}}}",
+                    pattern,
+                    pattern.count(),
+                    value
+                )
+            })
+            .collect();
+        return format!(
+            "{{\n  \"decoder\": \"{}\",\n  \"sum\": {},\n  \"memory\": [\n{}\n  ]\n}}\n",
+            match self.decoder {
+                Decoder::V1 => "v1",
+                Decoder::V2 => "v2",
+            },
+            self.memory.sum(),
+            rows.join(",\n")
+        );
+    }
+}
+
+/**
+ Addresses holding different values in two memories, None when an address was not written
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Difference {
+    pub addresses: AddressPattern,
+    pub left: Option<u64>,
+    pub right: Option<u64>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = |value: Option<u64>| value.map(|v| v.to_string()).unwrap_or("-".to_string());
+        return write!(
+            f,
+            "{} ({} address(es)): {} / {}",
+            self.addresses,
+            self.addresses.count(),
+            value(self.left),
+            value(self.right)
+        );
+    }
+}
+
+/**
+ Every difference between the `left` and the `right` memories, compared region by region
+*/
+pub fn diff(left: &FloatingMemory, right: &FloatingMemory) -> Vec<Difference> {
+    let mut res: Vec<Difference> = Vec::new();
+    for (left_region, left_value) in left.regions() {
+        for (right_region, right_value) in right.regions() {
+            if left_value == right_value {
+                continue;
+            }
+            if let Some(addresses) = left_region.intersection(right_region) {
+                res.push(Difference {
+                    addresses,
+                    left: Some(*left_value),
+                    right: Some(*right_value),
+                });
+            }
+        }
+    }
+    res.extend(
+        only_in(left, right)
+            .into_iter()
+            .map(|(addresses, value)| Difference {
+                addresses,
+                left: Some(value),
+                right: None,
+            }),
+    );
+    res.extend(
+        only_in(right, left)
+            .into_iter()
+            .map(|(addresses, value)| Difference {
+                addresses,
+                left: None,
+                right: Some(value),
+            }),
+    );
+    res.sort_by_key(|difference| (difference.addresses.fixed, difference.addresses.wildcard));
+    return res;
+}
+
+/**
+ The regions of `memory` that `other` does not write, cut where `other` writes
+*/
+fn only_in(memory: &FloatingMemory, other: &FloatingMemory) -> Vec<(AddressPattern, u64)> {
+    let mut res: Vec<(AddressPattern, u64)> = Vec::new();
+    for (region, value) in memory.regions() {
+        let mut rest = vec![*region];
+        for (other_region, _) in other.regions() {
+            rest = rest
+                .iter()
+                .flat_map(|pattern| pattern.subtract(other_region))
+                .collect();
+        }
+        res.extend(rest.into_iter().map(|pattern| (pattern, *value)));
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::Solution;
+
+    use crate::Day14;
+
+    const PROGRAM: &str =
+        "mask = 000000000000000000000000000000X1001X\nmem[42] = 100\nmask = 00000000000000000000000000000000X0XX\nmem[26] = 1";
+
+    #[test]
+    fn it_should_trace_writes() {
+        let program = Day14::parse(PROGRAM).unwrap();
+        let mut emulator = Emulator::with_trace(Decoder::V2);
+        emulator.run(&program);
+        assert_eq!(emulator.memory().sum(), 208);
+        assert_eq!(emulator.trace().len(), 2);
+        assert_eq!(
+            emulator.trace()[0].to_string(),
+            "#1 mem[42] = 100 with mask 000000000000000000000000000000X1001X: 100 at \
+             000000000000000000000000000000X1101X (4 address(es))"
+        );
+        assert!(emulator.trace()[1]
+            .to_string()
+            .ends_with(": 1 at 00000000000000000000000000000001X0XX (8 address(es))"));
+
+        let mut emulator = Emulator::new(Decoder::V1);
+        emulator.run(&program);
+        assert_eq!(emulator.decoder(), Decoder::V1);
+        assert_eq!(emulator.trace(), &[]);
+        assert_eq!(emulator.memory().read(42), Some(50));
+    }
+
+    #[test]
+    fn it_should_dump_memory_as_json() {
+        let mut emulator = Emulator::new(Decoder::V2);
+        emulator.run(&Day14::parse(PROGRAM).unwrap());
+        let json = emulator.to_json();
+        assert!(json.starts_with("{\n  \"decoder\": \"v2\",\n  \"sum\": 208,\n  \"memory\": [\n"));
+        assert!(json.contains(
+            "    {\"addresses\": \"00000000000000000000000000000001X0XX\", \"count\": 8, \"value\": 1}"
+        ));
+        assert!(json.ends_with("}\n  ]\n}\n"));
+    }
+
+    #[test]
+    fn it_should_diff_decoders() {
+        let program = Day14::parse(PROGRAM).unwrap();
+        let mut v1 = Emulator::new(Decoder::V1);
+        v1.run(&program);
+        let mut v2 = Emulator::new(Decoder::V2);
+        v2.run(&program);
+        let differences = diff(v1.memory(), v2.memory());
+        let count = |side: fn(&Difference) -> bool| -> u64 {
+            return differences
+                .iter()
+                .filter(|d| side(d))
+                .map(|d| d.addresses.count())
+                .sum();
+        };
+        // v1 writes 50 at 42 and 1 at 26, v2 writes 10 addresses including 1 at 26
+        assert_eq!(count(|d| d.left.is_some() && d.right.is_some()), 0);
+        assert_eq!(count(|d| d.right.is_none()), 1);
+        assert_eq!(count(|d| d.left.is_none()), 9);
+        assert_eq!(diff(v1.memory(), v1.memory()), vec![]);
+    }
+}
@@ -0,0 +1,210 @@
+use aoc_core::{lines, split_once, ParseError, Solution};
+
+use emulator::{Decoder, Emulator};
+use mask::Mask;
+
+pub mod emulator;
+pub mod mask;
+pub mod memory;
+
+const DAY: u8 = 14;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Op {
+    Mask(Mask),
+    Mem(u64, u64),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Op>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<Op>, ParseError> {
+    let parse_u64 = |s: &str| {
+        s.parse::<u64>()
+            .map_err(|_| ParseError::at(DAY, &input, s, "Should be a number"))
+    };
+    return lines(&input)
+        .map(|file_fragment| {
+            if let Some(mask) = file_fragment.strip_prefix("mask = ") {
+                // mask = 0X11XX1X010X01101000X01X011101100000
+                if mask.len() != Mask::WIDTH {
+                    return Err(ParseError::at(DAY, &input, mask, "Should be 36 bits long"));
+                }
+                let mut res = Mask::default();
+                for (i, c) in mask.char_indices() {
+                    let field = match c {
+                        '0' => &mut res.zeros,
+                        '1' => &mut res.ones,
+                        'X' => &mut res.floating,
+                        _ => {
+                            let fragment = &mask[i..i + c.len_utf8()];
+                            return Err(ParseError::at(
+                                DAY,
+                                &input,
+                                fragment,
+                                "Should be 0, 1 or X",
+                            ));
+                        }
+                    };
+                    // the first character is the most significant bit
+                    *field |= 1 << (mask.len() - 1 - i);
+                }
+                return Ok(Op::Mask(res));
+            }
+            // mem[4634] = 907
+            let (address, value) = file_fragment
+                .strip_prefix("mem[")
+                .and_then(|mem| split_once(mem, "] = "))
+                .ok_or_else(|| {
+                    ParseError::at(
+                        DAY,
+                        &input,
+                        file_fragment,
+                        "Should be \"mask = <mask>\" or \"mem[<address>] = <value>\"",
+                    )
+                })?;
+            return Ok(Op::Mem(parse_u64(address)?, parse_u64(value)?));
+        })
+        .collect();
+}
+
+fn part1(data: &Vec<Op>) -> u128 {
+    return execute1(data);
+}
+
+fn execute1(data: &Vec<Op>) -> u128 {
+    let mut emulator = Emulator::new(Decoder::V1);
+    emulator.run(data);
+    return emulator.memory().sum();
+}
+
+fn part2(data: &Vec<Op>) -> u128 {
+    return execute2(data);
+}
+
+fn execute2(data: &Vec<Op>) -> u128 {
+    let mut emulator = Emulator::new(Decoder::V2);
+    emulator.run(data);
+    return emulator.memory().sum();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        let input =
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0";
+        let expected = vec![
+            Op::Mask(Mask {
+                ones: 0b1000000,
+                zeros: 0b10,
+                floating: (1 << 36) - 1 - 0b1000010,
+            }),
+            Op::Mem(8, 11),
+            Op::Mem(7, 101),
+            Op::Mem(8, 0),
+        ];
+        assert_eq!(parse_data(input.to_string()).unwrap(), expected);
+    }
+
+    #[test]
+    fn it_should_reject_invalid_masks() {
+        let error = parse_data(format!("mask = {}", "X".repeat(70))).unwrap_err();
+        assert_eq!(error.message, "Should be 36 bits long");
+        let error = parse_data(format!("mask = {}2", "0".repeat(35))).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (43, "Should be 0, 1 or X")
+        );
+    }
+
+    #[test]
+    fn it_should_execute_correctly_1() {
+        let input =
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[8] = 11\nmem[7] = 101\nmem[8] = 0";
+        let data = parse_data(input.to_string()).unwrap();
+        assert_eq!(execute1(&data), 165);
+    }
+
+    fn mask(mask: &str) -> Mask {
+        let data = parse_data(format!("mask = {}", mask)).unwrap();
+        return match data[0] {
+            Op::Mask(mask) => mask,
+            Op::Mem(_, _) => panic!("Not a mask"),
+        };
+    }
+
+    #[test]
+    fn it_should_generate_all_masks_1() {
+        let mask = mask("00000000000000000000000000000000X0XX");
+
+        assert_eq!(
+            mask.addresses(0).collect::<Vec<u64>>(),
+            vec![
+                u64::from_str_radix("000000000000000000000000000000000000", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000000001", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000000010", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000000011", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000001000", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000001001", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000001010", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000001011", 2).unwrap(),
+            ]
+        )
+    }
+
+    #[test]
+    fn it_should_generate_all_masks_2() {
+        let mask = mask("000000000000000000000000000000X1001X");
+
+        assert_eq!(
+            mask.addresses(0).collect::<Vec<u64>>(),
+            vec![
+                u64::from_str_radix("000000000000000000000000000000010010", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000010011", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000110010", 2).unwrap(),
+                u64::from_str_radix("000000000000000000000000000000110011", 2).unwrap(),
+            ]
+        )
+    }
+
+    #[test]
+    fn it_should_apply_mask_correctly_1() {
+        let floating = mask("000000000000000000000000000000X1001X");
+        assert_eq!(
+            floating.addresses(42).collect::<Vec<u64>>(),
+            [26, 27, 58, 59]
+        );
+        assert_eq!(floating.to_string(), "000000000000000000000000000000X1001X");
+        let overwrite = mask("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X");
+        assert_eq!(overwrite.apply_value(11), 73);
+        assert_eq!(overwrite.apply_value(101), 101);
+    }
+
+    #[test]
+    fn it_should_execute_correctly_2() {
+        let input =
+            "mask = 000000000000000000000000000000X1001X\nmem[42] = 100\nmask = 00000000000000000000000000000000X0XX\nmem[26] = 1";
+        let data = parse_data(input.to_string()).unwrap();
+        assert_eq!(execute2(&data), 208);
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt;
+
+use crate::memory::AddressPattern;
+
+/**
+ A 36 bits mask: every bit is forced to 1, forced to 0 or floating (X), the three fields being
+ disjoint
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Mask {
+    pub ones: u64,
+    pub zeros: u64,
+    pub floating: u64,
+}
+
+impl Mask {
+    pub const WIDTH: usize = 36;
+
+    /**
+     Version 1 decoder: the value bits are overwritten by the 0 and 1 bits of the mask
+    */
+    pub fn apply_value(&self, value: u64) -> u64 {
+        return (value | self.ones) & !self.zeros;
+    }
+
+    /**
+     Version 2 decoder: the 1 bits of the mask are set in the address, the floating bits take
+     every combination of 0 and 1, in increasing order
+    */
+    pub fn addresses(&self, address: u64) -> impl Iterator<Item = u64> {
+        return self.address_pattern(address).addresses();
+    }
+
+    /**
+     The version 2 addresses as a pattern, without enumerating them
+    */
+    pub fn address_pattern(&self, address: u64) -> AddressPattern {
+        return AddressPattern::new(address | self.ones, self.floating);
+    }
+}
+
+impl fmt::Display for Mask {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for bit in (0..Mask::WIDTH).rev() {
+            let c = if self.floating >> bit & 1 == 1 {
+                'X'
+            } else if self.ones >> bit & 1 == 1 {
+                '1'
+            } else {
+                '0'
+            };
+            write!(f, "{}", c)?;
+        }
+        return Ok(());
+    }
+}
+
+/**
+ Every subset of the `set` bits, from 0 to `set` in increasing order
+*/
+pub fn subsets(set: u64) -> impl Iterator<Item = u64> {
+    // the next subset is the current one plus 1, carries going through the bits outside of `set`
+    return std::iter::successors(Some(0), move |current: &u64| {
+        let next = current.wrapping_sub(set) & set;
+        return if next == 0 { None } else { Some(next) };
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_enumerate_subsets() {
+        assert_eq!(
+            subsets(0b1011).collect::<Vec<u64>>(),
+            vec![0b0000, 0b0001, 0b0010, 0b0011, 0b1000, 0b1001, 0b1010, 0b1011]
+        );
+        assert_eq!(subsets(0).collect::<Vec<u64>>(), vec![0]);
+        assert_eq!(subsets(0xF0F0F).count(), 1 << 12);
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::mask::{subsets, Mask};
+
+/**
+ A set of addresses: the `fixed` bits are the same for all of them, the `wildcard` bits take
+ every value. `fixed` has no bit set among the `wildcard` ones.
+*/
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AddressPattern {
+    pub fixed: u64,
+    pub wildcard: u64,
+}
+
+impl AddressPattern {
+    pub fn new(fixed: u64, wildcard: u64) -> AddressPattern {
+        return AddressPattern {
+            fixed: fixed & !wildcard,
+            wildcard,
+        };
+    }
+
+    /**
+     Number of addresses in the pattern
+    */
+    pub fn count(&self) -> u64 {
+        return 1 << self.wildcard.count_ones();
+    }
+
+    pub fn contains(&self, address: u64) -> bool {
+        return address & !self.wildcard == self.fixed;
+    }
+
+    /**
+     Every address of the pattern, in increasing order
+    */
+    pub fn addresses(&self) -> impl Iterator<Item = u64> {
+        let fixed = self.fixed;
+        return subsets(self.wildcard).map(move |bits| fixed | bits);
+    }
+
+    /**
+     The addresses in both patterns, None when there is none
+    */
+    pub fn intersection(&self, other: &AddressPattern) -> Option<AddressPattern> {
+        let both_fixed = !self.wildcard & !other.wildcard;
+        if (self.fixed ^ other.fixed) & both_fixed != 0 {
+            return None;
+        }
+        // a bit fixed on one side only is 0 in the `fixed` field of the other
+        return Some(AddressPattern {
+            fixed: self.fixed | other.fixed,
+            wildcard: self.wildcard & other.wildcard,
+        });
+    }
+
+    /**
+     The addresses of `self` that are not in `other`, as disjoint patterns
+    */
+    pub fn subtract(&self, other: &AddressPattern) -> Vec<AddressPattern> {
+        if self.intersection(other).is_none() {
+            return vec![*self];
+        }
+        // for each wildcard bit of `self` that `other` fixes: the addresses agreeing with `other`
+        // on the previous such bits but not on this one
+        let mut res: Vec<AddressPattern> = Vec::new();
+        let mut current = *self;
+        let mut bits = self.wildcard & !other.wildcard;
+        while bits != 0 {
+            let bit = bits & bits.wrapping_neg();
+            bits &= !bit;
+            current.wildcard &= !bit;
+            res.push(AddressPattern {
+                fixed: current.fixed | (!other.fixed & bit),
+                wildcard: current.wildcard,
+            });
+            current.fixed |= other.fixed & bit;
+        }
+        return res;
+    }
+}
+
+impl fmt::Display for AddressPattern {
+    /**
+     In the mask notation: the 36 bits from the most significant one, X for the wildcard bits
+    */
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mask = Mask {
+            ones: self.fixed,
+            zeros: !self.fixed & !self.wildcard,
+            floating: self.wildcard,
+        };
+        return write!(f, "{}", mask);
+    }
+}
+
+/**
+ Memory written by address patterns: each region is a pattern and the value of all its
+ addresses, the regions being disjoint. A write cuts the older regions it overlaps, so the
+ memory never expands the patterns into addresses.
+*/
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FloatingMemory {
+    regions: Vec<(AddressPattern, u64)>,
+}
+
+impl FloatingMemory {
+    pub fn new() -> FloatingMemory {
+        return FloatingMemory::default();
+    }
+
+    /**
+     Write `value` at every address of `pattern`
+    */
+    pub fn write(&mut self, pattern: AddressPattern, value: u64) {
+        let mut cut: Vec<(AddressPattern, u64)> = Vec::new();
+        self.regions.retain(|(region, region_value)| {
+            if region.intersection(&pattern).is_none() {
+                return true;
+            }
+            cut.extend(
+                region
+                    .subtract(&pattern)
+                    .into_iter()
+                    .map(|rest| (rest, *region_value)),
+            );
+            return false;
+        });
+        self.regions.extend(cut);
+        self.regions.push((pattern, value));
+    }
+
+    pub fn read(&self, address: u64) -> Option<u64> {
+        return self
+            .regions
+            .iter()
+            .find(|(pattern, _)| pattern.contains(address))
+            .map(|(_, value)| *value);
+    }
+
+    pub fn regions(&self) -> &[(AddressPattern, u64)] {
+        return &self.regions;
+    }
+
+    /**
+     Number of addresses written
+    */
+    pub fn len(&self) -> u64 {
+        return self
+            .regions
+            .iter()
+            .map(|(pattern, _)| pattern.count())
+            .sum();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.regions.is_empty();
+    }
+
+    /**
+     Sum of the values of every address written
+    */
+    pub fn sum(&self) -> u128 {
+        return self
+            .regions
+            .iter()
+            .map(|(pattern, value)| pattern.count() as u128 * *value as u128)
+            .sum();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    #[test]
+    fn it_should_intersect_and_subtract_patterns() {
+        let a = AddressPattern::new(0b0010, 0b1101);
+        let b = AddressPattern::new(0b0011, 0b0100);
+        assert_eq!(
+            a.intersection(&b),
+            Some(AddressPattern::new(0b0011, 0b0100))
+        );
+        assert_eq!(a.intersection(&AddressPattern::new(0, 0)), None);
+
+        let rest = a.subtract(&b);
+        assert_eq!(rest.iter().map(|p| p.count()).sum::<u64>(), 8 - 2);
+        let mut addresses: Vec<u64> = rest.iter().flat_map(|p| p.addresses()).collect();
+        addresses.sort_unstable();
+        let expected: Vec<u64> = a.addresses().filter(|x| !b.contains(*x)).collect();
+        assert_eq!(addresses, expected);
+        assert_eq!(b.subtract(&a), vec![]);
+    }
+
+    #[test]
+    fn it_should_match_expanded_writes() {
+        let writes = [
+            (AddressPattern::new(0b100100, 0b011011), 7),
+            (AddressPattern::new(0b000001, 0b110000), 3),
+            (AddressPattern::new(0b001010, 0b000101), 11),
+            (AddressPattern::new(0b111111, 0), 2),
+            (AddressPattern::new(0, 0b101010), 5),
+        ];
+        let mut memory = FloatingMemory::new();
+        let mut expanded: HashMap<u64, u64> = HashMap::new();
+        for (pattern, value) in &writes {
+            memory.write(*pattern, *value);
+            for address in pattern.addresses() {
+                expanded.insert(address, *value);
+            }
+            assert_eq!(memory.len(), expanded.len() as u64);
+            assert_eq!(
+                memory.sum(),
+                expanded.values().map(|v| *v as u128).sum::<u128>()
+            );
+        }
+        for (address, value) in &expanded {
+            assert_eq!(memory.read(*address), Some(*value));
+        }
+        assert_eq!(memory.read(0b010000), None);
+    }
+
+    #[test]
+    fn it_should_write_many_floating_bits() {
+        let mut memory = FloatingMemory::new();
+        memory.write(AddressPattern::new(0, (1 << 36) - 1), 1);
+        memory.write(AddressPattern::new(1 << 35, (1 << 30) - 1), 3);
+        assert_eq!(memory.len(), 1 << 36);
+        assert_eq!(memory.sum(), (1 << 36) + 2 * (1 << 30));
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_core::{parse_numbers, ParseError, Solution};
+
+use van_eck::MemoryGame;
+
+pub mod van_eck;
+
+const DAY: u8 = 15;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Vec<u32>, ParseError> {
+    let numbers: Vec<u32> = parse_numbers(DAY, &input)?;
+    if numbers.is_empty() {
+        return Err(ParseError::at(
+            DAY,
+            &input,
+            &input[input.len()..],
+            "Should contain at least one starting number",
+        ));
+    }
+    return Ok(numbers);
+}
+
+fn part1(data: &Vec<u32>) -> u32 {
+    return run_to_nth(data, 2020);
+}
+
+/**
+ The `nth` number spoken (from 1) after the `starting` ones
+*/
+fn run_to_nth(starting: &[u32], nth: usize) -> u32 {
+    assert!(nth > 0, "Turns are counted from 1");
+    return MemoryGame::with_capacity(starting, nth)
+        .nth(nth - 1)
+        .expect("Should start with at least one number");
+}
+
+fn part2(data: &Vec<u32>) -> u32 {
+    return run_to_nth(data, 30000000);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_correctly() {
+        assert_eq!(
+            parse_data("13,16,0,12,15,1".to_string()).unwrap(),
+            vec![13, 16, 0, 12, 15, 1]
+        );
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_0_3_6() {
+        assert_eq!(run_to_nth(&[0, 3, 6], 2020), 436);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_1_3_2() {
+        assert_eq!(run_to_nth(&[1, 3, 2], 2020), 1);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_2_1_3() {
+        assert_eq!(run_to_nth(&[2, 1, 3], 2020), 10);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_1_2_3() {
+        assert_eq!(run_to_nth(&[1, 2, 3], 2020), 27);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_2_3_1() {
+        assert_eq!(run_to_nth(&[2, 3, 1], 2020), 78);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_3_2_1() {
+        assert_eq!(run_to_nth(&[3, 2, 1], 2020), 438);
+    }
+
+    #[test]
+    fn it_should_get_2020th_of_3_1_2() {
+        assert_eq!(run_to_nth(&[3, 1, 2], 2020), 1836);
+    }
+
+    #[test]
+    fn it_should_handle_short_games_and_large_starting_numbers() {
+        assert_eq!(run_to_nth(&[0, 3, 6], 2), 3);
+        assert_eq!(run_to_nth(&[0, 3, 6], 10), 0);
+        assert_eq!(run_to_nth(&[5000, 5000], 3), 1);
+        assert_eq!(run_to_nth(&[5000, 5000], 4), 0);
+        assert!(parse_data(" \n".to_string()).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+/**
+ The numbers spoken in the memory game (a Van Eck sequence seeded by the starting numbers), one
+ per turn, until turn `u32::MAX` as turns are stored as `u32`.
+
+ Only the previous number and the turn each number was last spoken are kept, in a table indexed
+ by number that grows with the largest number met.
+*/
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryGame {
+    starting: Vec<u32>,
+    turn: u32,             // turns played so far
+    previous: Option<u32>, // number spoken at `turn`, not in `last_seen` yet
+    last_seen: Vec<u32>,   // turn each number was last spoken before `turn`, 0 if never
+}
+
+impl MemoryGame {
+    pub fn new(starting: &[u32]) -> MemoryGame {
+        return MemoryGame::with_capacity(starting, 0);
+    }
+
+    /**
+     A game whose table is allocated once for `turns` turns: no number can be larger than the
+     turns played or the starting numbers
+    */
+    pub fn with_capacity(starting: &[u32], turns: usize) -> MemoryGame {
+        let largest = starting.iter().max().map(|n| *n as usize + 1).unwrap_or(0);
+        return MemoryGame {
+            starting: starting.to_vec(),
+            turn: 0,
+            previous: None,
+            last_seen: vec![0; turns.max(largest)],
+        };
+    }
+}
+
+impl Iterator for MemoryGame {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let turn = self.turn.checked_add(1)?;
+        let number = match (self.starting.get(turn as usize - 1), self.previous) {
+            (Some(number), _) => *number,
+            (None, Some(previous)) => match self.last_seen.get(previous as usize) {
+                Some(seen) if *seen > 0 => self.turn - seen,
+                _ => 0,
+            },
+            (None, None) => return None, // no starting number
+        };
+        if let Some(previous) = self.previous {
+            if previous as usize >= self.last_seen.len() {
+                let size = (previous as usize + 1).max(2 * self.last_seen.len());
+                self.last_seen.resize(size, 0);
+            }
+            self.last_seen[previous as usize] = self.turn;
+        }
+        self.previous = Some(number);
+        self.turn = turn;
+        return Some(number);
+    }
+}
+
+/**
+ Two turns where the same number was spoken, and not in between
+*/
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gap {
+    pub number: u32,
+    pub from: usize, // turns, from 1
+    pub to: usize,
+}
+
+/**
+ First turn (from 1) among the first `turns` where `number` is spoken
+*/
+pub fn first_occurrence(starting: &[u32], number: u32, turns: usize) -> Option<usize> {
+    return MemoryGame::with_capacity(starting, turns)
+        .take(turns)
+        .position(|n| n == number)
+        .map(|index| index + 1);
+}
+
+/**
+ How many times each number is spoken during the first `turns` turns, indexed by number
+*/
+pub fn histogram(starting: &[u32], turns: usize) -> Vec<usize> {
+    let mut res: Vec<usize> = Vec::new();
+    for number in MemoryGame::with_capacity(starting, turns).take(turns) {
+        if number as usize >= res.len() {
+            res.resize(number as usize + 1, 0);
+        }
+        res[number as usize] += 1;
+    }
+    return res;
+}
+
+/**
+ The longest wait before a number is spoken again during the first `turns` turns, the earliest
+ one on ties. Repeated starting numbers count too.
+*/
+pub fn largest_gap(starting: &[u32], turns: usize) -> Option<Gap> {
+    let mut res: Option<Gap> = None;
+    let mut last_seen: Vec<usize> = Vec::new(); // turn each number was last spoken, 0 if never
+    let game = MemoryGame::with_capacity(starting, turns).take(turns);
+    for (index, number) in game.enumerate() {
+        let turn = index + 1;
+        if number as usize >= last_seen.len() {
+            last_seen.resize(number as usize + 1, 0);
+        }
+        let from = std::mem::replace(&mut last_seen[number as usize], turn);
+        if from > 0 && res.is_none_or(|gap| turn - from > gap.to - gap.from) {
+            res = Some(Gap {
+                number,
+                from,
+                to: turn,
+            });
+        }
+    }
+    return res;
+}
+
+/**
+ The first time a run of `window` numbers is spoken again during the first `turns` turns: the
+ turns (from 1) where its first and its second occurrences start
+*/
+pub fn repeated_window(starting: &[u32], window: usize, turns: usize) -> Option<(usize, usize)> {
+    assert!(window > 0, "A window holds at least one number");
+    let numbers: Vec<u32> = MemoryGame::with_capacity(starting, turns)
+        .take(turns)
+        .collect();
+    let mut seen: HashMap<&[u32], usize> = HashMap::new();
+    for (start, run) in numbers.windows(window).enumerate() {
+        if let Some(first) = seen.insert(run, start) {
+            return Some((first + 1, start + 1));
+        }
+    }
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_iterate_spoken_numbers() {
+        assert_eq!(
+            MemoryGame::new(&[0, 3, 6]).take(10).collect::<Vec<u32>>(),
+            vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]
+        );
+        assert_eq!(MemoryGame::new(&[2, 1, 3]).nth(2019), Some(10));
+        assert_eq!(MemoryGame::new(&[]).next(), None);
+
+        let mut game = MemoryGame::new(&[0, 3, 6]);
+        game.turn = u32::MAX - 1;
+        game.previous = Some(0);
+        assert_eq!(game.next(), Some(0));
+        assert_eq!(game.next(), None);
+    }
+
+    #[test]
+    fn it_should_analyse_sequences() {
+        let starting = [0, 3, 6];
+        assert_eq!(first_occurrence(&starting, 4, 10), Some(9));
+        assert_eq!(first_occurrence(&starting, 5, 10), None);
+        assert_eq!(histogram(&starting, 10), vec![4, 1, 0, 3, 1, 0, 1]);
+        // 0 is spoken at turns 4 and 8
+        assert_eq!(
+            largest_gap(&starting, 10),
+            Some(Gap {
+                number: 0,
+                from: 4,
+                to: 8
+            })
+        );
+        assert_eq!(largest_gap(&starting, 3), None);
+        assert_eq!(
+            largest_gap(&[3, 1, 3, 2], 4),
+            Some(Gap {
+                number: 3,
+                from: 1,
+                to: 3
+            })
+        );
+        // 0, 3 at turns 1 and 4
+        assert_eq!(repeated_window(&starting, 2, 10), Some((1, 4)));
+        assert_eq!(repeated_window(&starting, 4, 10), None);
+    }
+}
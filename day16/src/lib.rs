@@ -1,10 +1,12 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::prelude::*;
 use std::ops::Range;
 
+use aoc_core::{lines, split_once, ParseError, Solution};
+
+const DAY: u8 = 16;
+
 #[derive(Clone, Debug, PartialEq)]
-struct TicketsData {
+pub struct TicketsData {
     fields: Vec<TicketsDataField>,
     own_ticket: Ticket,
     nearby_tickets: Vec<Ticket>,
@@ -18,56 +20,65 @@ struct TicketsDataField {
 
 type Ticket = Vec<u32>;
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day16;
 
-    part1(&data1);
-    println!("--------------------------------------------------");
-    part2(&data1);
-    Ok(())
-}
+impl Solution for Day16 {
+    type Input = TicketsData;
+    type Answer1 = u32;
+    type Answer2 = u128;
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
-}
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn parse_data(input: String) -> Result<TicketsData, String> {
-    let lines: Vec<String> = input
-        .split("\n")
-        .map(|file_fragment| file_fragment.trim())
-        .filter(|file_fragment| !file_fragment.is_empty())
-        .map(|file_fragment| file_fragment.to_string())
-        .collect();
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
 
-    let mut it = lines.iter();
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<TicketsData, ParseError> {
+    let parse_u32 = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| ParseError::at(DAY, &input, s, "Should be a number"))
+    };
+    // 7,1,14
+    let parse_ticket = |line: &str| -> Result<Ticket, ParseError> {
+        return line.split(',').map(|n| parse_u32(n.trim())).collect();
+    };
+    let missing = |what: &str| ParseError::at(DAY, &input, &input[input.len()..], what);
+    let mut it = lines(&input);
 
     let mut fields: Vec<TicketsDataField> = Vec::new();
-    while let Some(line) = it.next() {
+    for line in it.by_ref() {
         if line == "your ticket:" {
             break;
         }
-        let (label, ranges_str) = split_once(line, ":");
+        // departure location: 49-258 or 268-954
+        let (label, ranges_str) = split_once(line, ":").ok_or_else(|| {
+            ParseError::at(
+                DAY,
+                &input,
+                line,
+                "Should be formatted as \"<label>: <ranges>\"",
+            )
+        })?;
         let ranges: Vec<Range<u32>> = ranges_str
-            .split("or")
-            .map(|r| r.trim().to_string())
-            .filter(|r| !r.is_empty())
+            .split(" or ")
+            .map(|r| r.trim())
             .map(|r| {
-                let (rl, rr) = split_once(&r, "-");
-                return (
-                    rl.trim_matches(|c| c == ' ' || c == ':').to_string(),
-                    rr.trim_matches(|c| c == ' ' || c == '-').to_string(),
-                );
-            })
-            .map(|(rl, rr)| (rl.parse::<u32>().unwrap(), rr.parse::<u32>().unwrap()))
-            .map(|(rl, rr)| Range {
-                start: rl,
-                end: rr + 1,
+                let (rl, rr) = split_once(r, "-").ok_or_else(|| {
+                    ParseError::at(DAY, &input, r, "Should be formatted as \"<min>-<max>\"")
+                })?;
+                return Ok(Range {
+                    start: parse_u32(rl)?,
+                    end: parse_u32(rr)? + 1,
+                });
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
 
         fields.push(TicketsDataField {
             label: label.to_string(),
@@ -75,17 +86,22 @@ fn parse_data(input: String) -> Result<TicketsData, String> {
         });
     }
 
-    let own_ticket_line = it.next().unwrap();
-    let own_ticket: Ticket = own_ticket_line
-        .split(',')
-        .map(|u| u.parse::<u32>().unwrap())
-        .collect();
+    let own_ticket_line = it.next().ok_or_else(|| missing("Missing your ticket"))?;
+    let own_ticket: Ticket = parse_ticket(own_ticket_line)?;
 
-    it.next(); // skip 'nearby tickets:' line
-    let mut nearby_tickets: Vec<Ticket> = Vec::new();
-    while let Some(line) = it.next() {
-        nearby_tickets.push(line.split(',').map(|u| u.parse::<u32>().unwrap()).collect());
+    match it.next() {
+        Some("nearby tickets:") => {}
+        Some(line) => {
+            return Err(ParseError::at(
+                DAY,
+                &input,
+                line,
+                "Should be \"nearby tickets:\"",
+            ));
+        }
+        None => return Err(missing("Missing nearby tickets")),
     }
+    let nearby_tickets: Vec<Ticket> = it.map(parse_ticket).collect::<Result<_, ParseError>>()?;
 
     return Ok(TicketsData {
         fields,
@@ -94,26 +110,20 @@ fn parse_data(input: String) -> Result<TicketsData, String> {
     });
 }
 
-fn split_once<'a>(s: &'a String, separator: &str) -> (&'a str, &'a str) {
-    let separator_index = s.find(separator).unwrap();
-    return s.split_at(separator_index);
-}
-
-fn part1(data: &TicketsData) {
-    println!("Part1: {:?}", sum_invalid_ticket_value(data));
+fn part1(data: &TicketsData) -> u32 {
+    return sum_invalid_ticket_value(data);
 }
 
 fn sum_invalid_ticket_value(data: &TicketsData) -> u32 {
-    let rules: Vec<&Range<u32>> = data.fields.iter().map(|f| &f.ranges).flatten().collect();
+    let rules: Vec<&Range<u32>> = data.fields.iter().flat_map(|f| &f.ranges).collect();
     let res: u32 = data
         .nearby_tickets
         .iter()
-        .map(|t| -> Vec<&u32> {
+        .flat_map(|t| -> Vec<&u32> {
             t.iter()
-                .filter(|val| !match_any_range(&rules, *val))
+                .filter(|val| !match_any_range(&rules, val))
                 .collect()
         })
-        .flatten()
         .sum();
     return res;
 }
@@ -122,7 +132,7 @@ fn match_any_range(ranges: &Vec<&Range<u32>>, val: &u32) -> bool {
     return ranges.iter().any(|r| r.contains(val));
 }
 
-fn part2(data: &TicketsData) {
+fn part2(data: &TicketsData) -> u128 {
     let valid_nearby_tickets: Vec<&Ticket> = exclude_invalid_tickets(data);
     let fields_index_on_ticket =
         compute_fields_position(&data.fields, &data.own_ticket, &valid_nearby_tickets);
@@ -132,20 +142,15 @@ fn part2(data: &TicketsData) {
         .map(|(_, i)| *data.own_ticket.get(*i).unwrap())
         .map(|val| val as u128)
         .product();
-    println!("Part2: {}", multiply_departure_values);
+    return multiply_departure_values;
 }
 
 fn exclude_invalid_tickets(data: &TicketsData) -> Vec<&Ticket> {
-    let rules: Vec<&Range<u32>> = data.fields.iter().map(|f| &f.ranges).flatten().collect();
+    let rules: Vec<&Range<u32>> = data.fields.iter().flat_map(|f| &f.ranges).collect();
     let res: Vec<&Ticket> = data
         .nearby_tickets
         .iter()
-        .filter(|t| {
-            t.iter()
-                .filter(|val| !match_any_range(&rules, *val))
-                .count()
-                == 0
-        })
+        .filter(|t| t.iter().filter(|val| !match_any_range(&rules, val)).count() == 0)
         .collect();
     return res;
 }
@@ -169,7 +174,7 @@ fn compute_fields_position(
     let mut tickets_it = tickets.iter().rev();
     let mut found_field_indexes: Vec<usize> = Vec::new();
     loop {
-        if fields_indexes.iter().map(|(_, v)| v.len()).sum::<usize>() == fields_indexes.len() {
+        if fields_indexes.values().map(|v| v.len()).sum::<usize>() == fields_indexes.len() {
             break;
         }
         if let Some(ticket) = tickets_it.next() {
@@ -185,7 +190,7 @@ fn compute_fields_position(
                         let val = ticket.get(**i).unwrap();
                         return field.ranges.iter().any(|r| r.contains(val));
                     })
-                    .map(|u| *u)
+                    .copied()
                     .collect();
                 if eventual_indexes.len() == 1 {
                     found_field_indexes.push(*eventual_indexes.last().unwrap());
@@ -205,7 +210,7 @@ fn compute_fields_position(
             *indexes = indexes
                 .iter()
                 .filter(|i| !found_field_indexes.contains(i))
-                .map(|u| *u)
+                .copied()
                 .collect();
             if indexes.len() == 1 {
                 found_new = true;
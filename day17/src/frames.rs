@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use aoc_core::{Animation, Grid};
+
+use crate::{cycles, is_active, Cube, CubeState};
+
+const PALETTE: [[u8; 3]; 3] = [
+    [90, 90, 90],   // gap between slices
+    [20, 20, 40],   // inactive cube
+    [250, 210, 60], // active cube
+];
+
+/**
+ Bounds of the cubes that were active at some cycle: (min x, max x), (min y, max y) and the
+ highest coordinate on the other axes (the space being symmetric along them)
+*/
+struct Bounds {
+    x: (i64, i64),
+    y: (i64, i64),
+    radius: i64,
+}
+
+impl Bounds {
+    fn new(history: &[HashSet<Cube>]) -> Bounds {
+        let cubes = || history.iter().flatten();
+        let range = |axis: usize| -> (i64, i64) {
+            return (
+                cubes().map(|cube| cube[axis]).min().unwrap_or(0),
+                cubes().map(|cube| cube[axis]).max().unwrap_or(0),
+            );
+        };
+        return Bounds {
+            x: range(0),
+            y: range(1),
+            radius: cubes()
+                .flat_map(|cube| cube[2..].to_vec())
+                .max()
+                .unwrap_or(0),
+        };
+    }
+
+    /**
+     Coordinates on the higher axes of every slice, z varying the fastest
+    */
+    fn slices(&self, dimensions: usize) -> Vec<Vec<i64>> {
+        let mut res: Vec<Vec<i64>> = vec![Vec::new()];
+        for _ in 2..dimensions {
+            res = (-self.radius..=self.radius)
+                .flat_map(|value| {
+                    return res.iter().map(move |slice| {
+                        let mut next = slice.clone();
+                        next.push(value);
+                        return next;
+                    });
+                })
+                .collect();
+        }
+        return res;
+    }
+
+    fn slice(&self, cubes: &HashSet<Cube>, higher: &[i64]) -> Grid<CubeState> {
+        let width = (self.x.1 - self.x.0 + 1) as usize;
+        let height = (self.y.1 - self.y.0 + 1) as usize;
+        let states = (0..width * height)
+            .map(|index| {
+                let mut cube: Cube = vec![
+                    self.x.0 + (index % width) as i64,
+                    self.y.0 + (index / width) as i64,
+                ];
+                cube.extend(higher);
+                if is_active(cubes, &cube) {
+                    return CubeState::Active;
+                }
+                return CubeState::Inactive;
+            })
+            .collect();
+        return Grid::new(width, height, states);
+    }
+}
+
+fn slice_title(higher: &[i64]) -> String {
+    return higher
+        .iter()
+        .enumerate()
+        .map(|(axis, value)| match axis {
+            0 => format!("z={}", value),
+            1 => format!("w={}", value),
+            _ => format!("d{}={}", axis + 3, value),
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+}
+
+/**
+ Every cycle as text in the puzzle notation: each slice of the space after its coordinates on
+ the higher axes, all cycles sharing the same bounds
+*/
+pub fn text_frames(data: &Grid<CubeState>, dimensions: usize, cycle_count: usize) -> String {
+    let history = cycles(data, dimensions, cycle_count);
+    let bounds = Bounds::new(&history);
+    let mut res: Vec<String> = Vec::new();
+    for (cycle, cubes) in history.iter().enumerate() {
+        let slices: Vec<String> = bounds
+            .slices(dimensions)
+            .iter()
+            .map(|higher| {
+                let slice = bounds.slice(cubes, higher).render(|state| match state {
+                    CubeState::Active => '#',
+                    CubeState::Inactive => '.',
+                });
+                return format!("{}\n{}", slice_title(higher), slice);
+            })
+            .collect();
+        res.push(format!("Cycle {}\n{}", cycle, slices.join("\n")));
+    }
+    return res.join("\n");
+}
+
+/**
+ Every cycle as an animation frame: the slices side by side, z growing to the right and the
+ other axes downwards
+*/
+pub fn animation(data: &Grid<CubeState>, dimensions: usize, cycle_count: usize) -> Animation {
+    let history = cycles(data, dimensions, cycle_count);
+    let bounds = Bounds::new(&history);
+    let slices = bounds.slices(dimensions);
+    let columns = if dimensions > 2 {
+        (2 * bounds.radius + 1) as usize
+    } else {
+        1
+    };
+    let rows = slices.len() / columns;
+    let mut res = Animation::new(PALETTE.to_vec(), 50, 6);
+    for cubes in &history {
+        let grids: Vec<Grid<CubeState>> = slices
+            .iter()
+            .map(|higher| bounds.slice(cubes, higher))
+            .collect();
+        let (width, height) = (grids[0].width(), grids[0].height());
+        // one gap cell between two slices
+        let mut frame = Grid::new(
+            columns * (width + 1) - 1,
+            rows * (height + 1) - 1,
+            vec![0; (columns * (width + 1) - 1) * (rows * (height + 1) - 1)],
+        );
+        for (index, grid) in grids.iter().enumerate() {
+            let left = (index % columns) * (width + 1);
+            let top = (index / columns) * (height + 1);
+            for (x, y) in grid.positions() {
+                frame[(left + x, top + y)] = match grid[(x, y)] {
+                    CubeState::Active => 2,
+                    CubeState::Inactive => 1,
+                };
+            }
+        }
+        res.push(frame);
+    }
+    return res;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aoc_core::Solution;
+
+    use crate::Day17;
+
+    #[test]
+    fn it_should_export_every_slice() {
+        let data = Day17::parse(".#.\n..#\n###\n").unwrap();
+        let text = text_frames(&data, 3, 1);
+        assert!(text.starts_with("Cycle 0\nz=-1\n...\n...\n...\n...\n\nz=0\n.#.\n..#\n###\n"));
+        assert!(text.contains("Cycle 1\nz=-1\n...\n#..\n..#\n.#.\n\nz=0\n"));
+
+        let text = text_frames(&data, 4, 1);
+        assert!(text.contains("\nz=1, w=-1\n...\n#..\n..#\n.#.\n"));
+
+        let animation = animation(&data, 4, 2);
+        assert_eq!(animation.frames.len(), 3);
+        // 5x5 slices of 5x5 cubes (radius 2 after 2 cycles)
+        assert_eq!(animation.frames[0].width(), 5 * 6 - 1);
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashSet;
+
+use aoc_core::{Automaton, Grid, LifeRule, ParseError, Solution};
+
+pub mod frames;
+
+const DAY: u8 = 17;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CubeState {
+    Active,
+    Inactive,
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<CubeState>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
+}
+
+fn parse_data(input: String) -> Result<Grid<CubeState>, ParseError> {
+    return Grid::parse(
+        DAY,
+        &input,
+        |c| match c {
+            '#' => Some(CubeState::Active),
+            '.' => Some(CubeState::Inactive),
+            _ => None,
+        },
+        "Invalid cube state",
+    );
+}
+
+fn part1(data: &Grid<CubeState>) -> usize {
+    return active_cubes(data, 3, 6);
+}
+
+fn part2(data: &Grid<CubeState>) -> usize {
+    return active_cubes(data, 4, 6);
+}
+
+/**
+ Position of a cube: x, y, then the coordinates on the higher axes (z, w...)
+*/
+pub type Cube = Vec<i64>;
+
+/**
+ Count of active cubes after `cycle_count` cycles in a space of `dimensions` (at least 2), the
+ starting slice being at 0 on every higher axis.
+
+ As everything starts at 0 on the higher axes, the space stays symmetric along them: flipping
+ the sign of a higher coordinate or swapping two of them gives a cube in the same state. Only
+ one cube of each such class is simulated (see `canonical`), `orbit_size` gives back the number
+ of cubes it stands for.
+*/
+pub fn active_cubes(data: &Grid<CubeState>, dimensions: usize, cycle_count: usize) -> usize {
+    let end_cubes = cycles(data, dimensions, cycle_count).pop().unwrap();
+    return end_cubes.iter().map(|cube| orbit_size(cube)).sum();
+}
+
+/**
+ Active cubes of every cycle from the start to `cycle_count`, a single cube of each class of
+ symmetric cubes: use `is_active` to know the state of any cube
+*/
+pub fn cycles(data: &Grid<CubeState>, dimensions: usize, cycle_count: usize) -> Vec<HashSet<Cube>> {
+    assert!(dimensions >= 2, "Cubes need at least 2 dimensions");
+    let offsets = neighbor_offsets(dimensions);
+    let neighbourhood = |cube: &Cube| -> Vec<Cube> {
+        return get_neighbors_position(cube, &offsets)
+            .into_iter()
+            .map(canonical)
+            .collect();
+    };
+    let mut automaton = Automaton::new(conway_cubes(), neighbourhood);
+    automaton.weight = Some(|cube: &Cube| orbit_size(cube));
+    return automaton
+        .generations(init_active_cubes(data, dimensions))
+        .take(cycle_count + 1)
+        .collect();
+}
+
+pub fn is_active(cubes: &HashSet<Cube>, cube: &[i64]) -> bool {
+    return cubes.contains(&canonical(cube.to_vec()));
+}
+
+fn init_active_cubes(data: &Grid<CubeState>, dimensions: usize) -> HashSet<Cube> {
+    return data
+        .positions()
+        .filter(|p| data[*p] == CubeState::Active)
+        .map(|(x, y)| {
+            let mut cube: Cube = vec![0; dimensions];
+            cube[0] = x as i64;
+            cube[1] = y as i64;
+            return cube;
+        })
+        .collect();
+}
+
+/**
+ Every move to a neighbor in `dimensions`, the first axis varying the slowest
+*/
+fn neighbor_offsets(dimensions: usize) -> Vec<Cube> {
+    let mut offsets: Vec<Cube> = vec![Vec::new()];
+    for _ in 0..dimensions {
+        offsets = offsets
+            .into_iter()
+            .flat_map(|offset| {
+                return (-1..=1).map(move |delta| {
+                    let mut next = offset.clone();
+                    next.push(delta);
+                    return next;
+                });
+            })
+            .collect();
+    }
+    offsets.retain(|offset| offset.iter().any(|delta| *delta != 0));
+    return offsets;
+}
+
+fn get_neighbors_position(cube: &[i64], offsets: &[Cube]) -> Vec<Cube> {
+    return offsets
+        .iter()
+        .map(|offset| cube.iter().zip(offset).map(|(c, d)| c + d).collect())
+        .collect();
+}
+
+/**
+ Representative of the cubes that are symmetric to `cube`: the higher coordinates made positive
+ and sorted
+*/
+fn canonical(mut cube: Cube) -> Cube {
+    for coordinate in cube.iter_mut().skip(2) {
+        *coordinate = coordinate.abs();
+    }
+    cube[2..].sort_unstable();
+    return cube;
+}
+
+/**
+ Number of distinct cubes having `cube` as canonical representative: the distinct orderings of
+ its higher coordinates, times a sign choice for each one that is not 0
+*/
+fn orbit_size(cube: &[i64]) -> usize {
+    let higher = &cube[2..];
+    let factorial = |n: usize| -> usize { (1..=n).product() };
+    let mut permutations = factorial(higher.len());
+    let mut start = 0;
+    while start < higher.len() {
+        let repeated = higher[start..]
+            .iter()
+            .take_while(|c| **c == higher[start])
+            .count();
+        permutations /= factorial(repeated);
+        start += repeated;
+    }
+    let signs = higher.iter().filter(|c| **c != 0).count();
+    return permutations << signs;
+}
+
+/**
+ Conway cubes rule: an active cube stays active with 2 or 3 active neighbors, an inactive one
+ becomes active with exactly 3
+*/
+fn conway_cubes() -> LifeRule {
+    return LifeRule::parse("B3/S23").unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_compute_neighbors_correctly() {
+        let neighbors = vec![
+            vec![-1, -1, -1],
+            vec![-1, -1, 0],
+            vec![-1, -1, 1],
+            vec![-1, 0, -1],
+            vec![-1, 0, 0],
+            vec![-1, 0, 1],
+            vec![-1, 1, -1],
+            vec![-1, 1, 0],
+            vec![-1, 1, 1],
+            vec![0, -1, -1],
+            vec![0, -1, 0],
+            vec![0, -1, 1],
+            vec![0, 0, -1],
+            vec![0, 0, 1],
+            vec![0, 1, -1],
+            vec![0, 1, 0],
+            vec![0, 1, 1],
+            vec![1, -1, -1],
+            vec![1, -1, 0],
+            vec![1, -1, 1],
+            vec![1, 0, -1],
+            vec![1, 0, 0],
+            vec![1, 0, 1],
+            vec![1, 1, -1],
+            vec![1, 1, 0],
+            vec![1, 1, 1],
+        ];
+        assert_eq!(
+            get_neighbors_position(&[0, 0, 0], &neighbor_offsets(3)),
+            neighbors
+        );
+        assert_eq!(neighbor_offsets(5).len(), 242);
+    }
+
+    #[test]
+    fn it_should_count_symmetric_cubes() {
+        assert_eq!(canonical(vec![3, -1, 2, -5, 0]), vec![3, -1, 0, 2, 5]);
+        assert_eq!(orbit_size(&[3, -1, 0]), 1);
+        assert_eq!(orbit_size(&[3, -1, 1, 0]), 4);
+        assert_eq!(orbit_size(&[3, -1, 1, 1]), 4);
+        assert_eq!(orbit_size(&[3, -1, 0, 1, 2]), 24);
+    }
+
+    #[test]
+    fn it_should_simulate_any_dimension() {
+        let data = Day17::parse(".#.\n..#\n###\n").unwrap();
+        assert_eq!(active_cubes(&data, 3, 6), 112);
+        assert_eq!(active_cubes(&data, 4, 6), 848);
+        assert_eq!(active_cubes(&data, 5, 6), 5760);
+        assert_eq!(active_cubes(&data, 6, 6), 35936);
+        assert_eq!(active_cubes(&data, 2, 1), 5);
+    }
+}
@@ -1,9 +1,11 @@
 use std::collections::VecDeque;
-use std::fs::File;
-use std::io::prelude::*;
+
+use aoc_core::{lines, ParseError, Solution};
+
+const DAY: u8 = 18;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum OpUnit {
+pub enum OpUnit {
     Operator(Operator),
     Operand(u128),
     OpenBracket,
@@ -11,58 +13,59 @@ enum OpUnit {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
-enum Operator {
+pub enum Operator {
     Add,
     Multiply,
 }
 
-type Operation = Vec<OpUnit>;
+pub type Operation = Vec<OpUnit>;
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day18;
 
-    part1(&data1);
-    println!("--------------------------------------------------");
-    part2(&data1);
-    Ok(())
-}
+impl Solution for Day18 {
+    type Input = Vec<Operation>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<Vec<Operation>, String> {
-    let parsed = input
-        .split("\n")
-        .map(|file_fragment| file_fragment.trim())
-        .filter(|file_fragment| !file_fragment.is_empty())
-        .map(|file_fragment| -> Operation {
+fn parse_data(input: String) -> Result<Vec<Operation>, ParseError> {
+    return lines(&input)
+        .map(|file_fragment| {
             return file_fragment
-                .chars()
-                .filter(|c| !c.is_whitespace())
-                .map(|c| match c {
-                    n if n.is_numeric() => Ok(OpUnit::Operand(n.to_digit(10).unwrap() as u128)),
+                .char_indices()
+                .filter(|(_, c)| !c.is_whitespace())
+                .map(|(i, c)| match c {
+                    n if n.is_ascii_digit() => Ok(OpUnit::Operand(n.to_digit(10).unwrap() as u128)),
                     '+' => Ok(OpUnit::Operator(Operator::Add)),
                     '*' => Ok(OpUnit::Operator(Operator::Multiply)),
                     '(' => Ok(OpUnit::OpenBracket),
                     ')' => Ok(OpUnit::CloseBracket),
-                    _ => Err("Invalid"),
+                    any => Err(ParseError::at(
+                        DAY,
+                        &input,
+                        &file_fragment[i..i + any.len_utf8()],
+                        "Invalid operation character",
+                    )),
                 })
-                .filter(|unit| unit.is_ok())
-                .map(|unit| unit.unwrap())
                 .collect();
         })
         .collect();
-    return Ok(parsed);
 }
 
-fn part1(data: &Vec<Operation>) {
-    let res: u128 = data.iter().map(|operation| compute(operation)).sum();
-    println!("Part1: {:?}", res);
+fn part1(data: &Vec<Operation>) -> u128 {
+    return data.iter().map(compute).sum();
 }
 
 fn compute(operation: &Operation) -> u128 {
@@ -88,12 +91,12 @@ fn compute(operation: &Operation) -> u128 {
                     let next_unit = op.get(i + 1);
                     match next_unit {
                         Some(OpUnit::Operand(operand)) => {
-                            res = res + operand;
+                            res += operand;
                             i += 2;
                         }
                         Some(OpUnit::OpenBracket) => {
                             let (operand, new_i) = compute_rec(op, i + 2);
-                            res = res + operand;
+                            res += operand;
                             i = new_i;
                         }
                         _ => panic!("Invalid OpUnit position [101]"),
@@ -103,12 +106,12 @@ fn compute(operation: &Operation) -> u128 {
                     let next_unit = op.get(i + 1);
                     match next_unit {
                         Some(OpUnit::Operand(operand)) => {
-                            res = res * operand;
+                            res *= operand;
                             i += 2;
                         }
                         Some(OpUnit::OpenBracket) => {
                             let (operand, new_i) = compute_rec(op, i + 2);
-                            res = res * operand;
+                            res *= operand;
                             i = new_i;
                         }
                         _ => panic!("Invalid OpUnit position [201]"),
@@ -122,9 +125,8 @@ fn compute(operation: &Operation) -> u128 {
     return compute_rec(operation, 0).0;
 }
 
-fn part2(data: &Vec<Operation>) {
-    let res: u128 = data.iter().map(|operation| compute2(operation)).sum();
-    println!("Part2: {:?}", res);
+fn part2(data: &Vec<Operation>) -> u128 {
+    return data.iter().map(compute2).sum();
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
@@ -197,7 +199,7 @@ fn compute2(operation: &Operation) -> u128 {
                     let next_unit = op_no_add.get(j + 1);
                     match next_unit {
                         Some(OpUnit2::Operand(operand)) => {
-                            res = res * operand;
+                            res *= operand;
                             j += 2;
                         }
                         _ => panic!("Invalid OpUnit position [1201]"),
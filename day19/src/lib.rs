@@ -1,9 +1,11 @@
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::prelude::*;
+
+use aoc_core::{split_once, ParseError, Solution};
+
+const DAY: u8 = 19;
 
 #[derive(Clone, Debug, PartialEq)]
-struct Data {
+pub struct Data {
     rules: Vec<Rule>,
     messages: Vec<String>,
 }
@@ -25,65 +27,78 @@ impl RuleRef {
     }
 }
 
-fn main() -> std::io::Result<()> {
-    let input1 = read_input("input1.txt").expect("An error occurred when reading input1.txt");
-    let data1 = parse_data(input1).expect("An error occurred when parsing input1.txt");
+pub struct Day19;
 
-    part1(&data1);
-    println!("--------------------------------------------------");
-    part2(&data1);
-    Ok(())
-}
+impl Solution for Day19 {
+    type Input = Data;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        return parse_data(input.to_string());
+    }
 
-fn read_input(file_name: &str) -> std::io::Result<String> {
-    let mut file = File::open(file_name)?;
-    let mut contents = String::new();
-    file.read_to_string(&mut contents)?;
-    return Ok(contents);
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        return part1(input);
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        return part2(input);
+    }
 }
 
-fn parse_data(input: String) -> Result<Data, String> {
+fn parse_data(input: String) -> Result<Data, ParseError> {
+    let parse_u32 = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| ParseError::at(DAY, &input, s, "Should be a rule id"))
+    };
     let mut rules: Vec<Rule> = Vec::new();
     let mut messages: Vec<String> = Vec::new();
 
     let mut lines = input.split("\n");
 
-    while let Some(raw_line) = lines.next() {
+    for raw_line in lines.by_ref() {
         let line: &str = raw_line.trim();
         if line.is_empty() {
             break;
         }
-        let colon_index: usize = line.find(":").unwrap();
-        let (rule_id_str, rest) = line.split_at(colon_index);
-        let rule_id: u32 = rule_id_str.parse::<u32>().unwrap();
+        // 4: 1 2 | 2 1
+        // 1: "a"
+        let (rule_id_str, rest) = split_once(line, ":").ok_or_else(|| {
+            ParseError::at(DAY, &input, line, "Should be formatted as \"<id>: <rule>\"")
+        })?;
+        let rule_id: u32 = parse_u32(rule_id_str)?;
 
         if rest.contains('"') {
-            rules.push(Rule::Leaf(
-                rule_id,
-                rest.trim_start_matches(":")
-                    .trim()
-                    .trim_matches('"')
-                    .chars()
-                    .last()
-                    .unwrap(),
-            ));
+            let leaf = rest.trim().trim_matches('"');
+            let mut chars = leaf.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => rules.push(Rule::Leaf(rule_id, c)),
+                _ => {
+                    return Err(ParseError::at(
+                        DAY,
+                        &input,
+                        leaf,
+                        "Should be a single quoted character",
+                    ))
+                }
+            }
         } else {
             let patterns: Vec<Vec<RuleRef>> = rest
-                .trim_start_matches(":")
                 .split("|")
-                .map(|sub_pattern| -> Vec<RuleRef> {
+                .map(|sub_pattern| {
                     return sub_pattern
                         .split(" ")
                         .map(|fragment| fragment.trim())
                         .filter(|fragment| !fragment.is_empty())
-                        .map(|fragment| RuleRef::new(fragment.parse::<u32>().unwrap()))
+                        .map(|fragment| Ok(RuleRef::new(parse_u32(fragment)?)))
                         .collect();
                 })
-                .collect();
-            rules.push(Rule::Composite(rule_id, patterns.clone()));
+                .collect::<Result<_, ParseError>>()?;
+            rules.push(Rule::Composite(rule_id, patterns));
         }
     }
-    while let Some(raw_line) = lines.next() {
+    for raw_line in lines {
         let line: &str = raw_line.trim();
         if line.is_empty() {
             break;
@@ -94,7 +109,7 @@ fn parse_data(input: String) -> Result<Data, String> {
     return Ok(Data { rules, messages });
 }
 
-fn part1(data: &Data) {
+fn part1(data: &Data) -> usize {
     let rules_index = index_rules(data);
     let valid_message_count = data
         .messages
@@ -102,7 +117,7 @@ fn part1(data: &Data) {
         .map(|m| match_rule(&rules_index, 0, m))
         .filter(|is_matching| *is_matching)
         .count();
-    println!("Part1: {:?}", valid_message_count);
+    return valid_message_count;
 }
 
 fn index_rules(data: &Data) -> HashMap<u32, &Rule> {
@@ -120,80 +135,65 @@ fn index_rules(data: &Data) -> HashMap<u32, &Rule> {
 }
 
 fn match_rule(rules_index: &HashMap<u32, &Rule>, rule: u32, msg: &String) -> bool {
+    /**
+     Return every message index where matching `rule_rec` from `msg_i` can end,
+     a rule can match several prefixes (8: 42 | 42 8) so we have to keep all of them.
+     `active` holds the (rule, index) being matched by the callers: reaching one again
+     consumed nothing in between (0: 1, 1: 0) and would recurse forever, so it matches nothing
+    */
     fn match_rule_rec(
         rules_index_rec: &HashMap<u32, &Rule>,
-        msg_rec: &String,
+        msg_rec: &[char],
         rule_rec: u32,
         msg_i: usize,
-        depth: usize,
-    ) -> (bool, usize) {
+        active: &mut Vec<(u32, usize)>,
+    ) -> Vec<usize> {
         if msg_i >= msg_rec.len() {
-            println!("index out of bound {}/{}", msg_i, msg_rec);
-            return (false, msg_i);
+            return Vec::new();
         }
-        let indent: String = (0..depth)
-            .into_iter()
-            .map(|_| -> &str { "  " })
-            .fold("".to_string(), |prev, c| format!("{}{}", prev, c));
-        println!(
-            "{}match_rule_rec(rule:{},msg_i:{})",
-            indent, rule_rec, msg_i
-        );
         return match rules_index_rec.get(&rule_rec) {
             Some(Rule::Leaf(_, c)) => {
-                let res = msg_rec.chars().nth(msg_i).unwrap() == *c;
-                println!(
-                    "{}leaf {:?}({}) = {:?} => {}",
-                    indent,
-                    msg_rec.chars().nth(msg_i).unwrap(),
-                    msg_i,
-                    c,
-                    res
-                );
-                return (res, msg_i + 1);
+                if msg_rec[msg_i] == *c {
+                    vec![msg_i + 1]
+                } else {
+                    Vec::new()
+                }
             }
             Some(Rule::Composite(_, patterns)) => {
-                println!("{}any {:?}/{}", indent, patterns, msg_i);
-                let mut msg_i_res = msg_i;
-                let res_pattern = patterns.iter().any(|sub_pattern| {
-                    println!("{}all {:?}/{}", indent, sub_pattern, msg_i);
-                    let mut msg_i2 = msg_i;
-                    let res_sub_pattern = sub_pattern.iter().all(|rule_ref| {
-                        let (sub_pattern_res, msg_i3) = match_rule_rec(
-                            rules_index_rec,
-                            msg_rec,
-                            rule_ref.id,
-                            msg_i2,
-                            depth + 1,
-                        );
-                        if sub_pattern_res {
-                            msg_i2 = msg_i3;
+                if active.contains(&(rule_rec, msg_i)) {
+                    return Vec::new();
+                }
+                active.push((rule_rec, msg_i));
+                let mut ends: Vec<usize> = Vec::new();
+                for sub_pattern in patterns {
+                    let mut msg_indexes = vec![msg_i];
+                    for rule_ref in sub_pattern {
+                        let mut next_indexes: Vec<usize> = Vec::new();
+                        for i in msg_indexes {
+                            next_indexes.extend(match_rule_rec(
+                                rules_index_rec,
+                                msg_rec,
+                                rule_ref.id,
+                                i,
+                                active,
+                            ));
                         }
-                        return sub_pattern_res;
-                    });
-                    println!(
-                        "{}all {:?}/{} => {}",
-                        indent, sub_pattern, msg_i2, res_sub_pattern
-                    );
-                    if res_sub_pattern {
-                        msg_i_res = msg_i2;
+                        msg_indexes = next_indexes;
                     }
-                    res_sub_pattern
-                });
-                println!("{}any {:?}/{} => {}", indent, patterns, msg_i, res_pattern);
-                (res_pattern, msg_i_res)
-            }
-            _ => {
-                println!("Invalid rule index: {}", rule_rec);
-                return (false, msg_i);
+                    ends.extend(msg_indexes);
+                }
+                active.pop();
+                ends
             }
+            None => Vec::new(), // unknown rule, nothing matches
         };
     }
-    let (msg_valid, msg_index) = match_rule_rec(rules_index, msg, rule, 0, 0);
-    return msg_valid && msg_index >= msg.len();
+    let msg_chars: Vec<char> = msg.chars().collect();
+    let ends = match_rule_rec(rules_index, &msg_chars, rule, 0, &mut Vec::new());
+    return ends.contains(&msg_chars.len());
 }
 
-fn part2(data: &Data) {
+fn part2(data: &Data) -> usize {
     let mut rules_index = index_rules(data);
     // 8: 42 | 42 8
     let rule8 = Rule::Composite(
@@ -219,7 +219,7 @@ fn part2(data: &Data) {
         .map(|m| match_rule(&rules_index, 0, m))
         .filter(|is_matching| *is_matching)
         .count();
-    println!("Part2: {:?}", valid_message_count);
+    return valid_message_count;
 }
 
 #[cfg(test)]
@@ -419,7 +419,6 @@ mod tests {
     fn it_should_match_rule_1_2() {
         assert!(msg_should_match_rule_1("babbbbaabbbbbabbbbbbaabaaabaaa"));
     }
-    /*
     #[test]
     fn it_should_match_rule_1_3() {
         assert!(msg_should_match_rule_1(
@@ -483,5 +482,12 @@ mod tests {
     fn it_should_match_rule_1_15() {
         assert!(!msg_should_match_rule_1("babaaabbbaaabaababbaabababaaab"));
     }
-    */
+
+    #[test]
+    fn it_should_not_loop_on_rules_consuming_nothing() {
+        let rules = parse_data("0: 1\n1: 0\n\na".to_string()).unwrap();
+        assert_eq!(part1(&rules), 0);
+        let rules = parse_data("0: 1 2\n1: 0 | 2\n2: \"a\"\n\na\naa\naaa".to_string()).unwrap();
+        assert_eq!(part1(&rules), 1);
+    }
 }